@@ -853,6 +853,12 @@ pub fn drain_sorted(&mut self) -> DrainSorted<'_, T> {
     ///
     /// assert_eq!(heap.into_sorted_vec(), [-10, 2, 4])
     /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// The predicate is called once per element, and restoring the heap
+    /// afterwards costs at most *O*(*n*), so the whole operation is *O*(*n*).
+    /// Only the part of the heap after the first removed element is rebuilt.
     #[stable(feature = "binary_heap_retain", since = "CURRENT_RUSTC_VERSION")]
     pub fn retain<F>(&mut self, mut f: F)
     where
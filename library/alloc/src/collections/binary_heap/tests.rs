@@ -80,6 +80,53 @@ fn test_drain_sorted_collect() {
     assert_eq!(sorted, vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 2, 1, 1, 0]);
 }
 
+#[test]
+fn test_sorted_iterators_are_lazy() {
+    use core::cell::Cell;
+    use core::cmp::Ordering;
+
+    struct Counted<'a>(u32, &'a Cell<usize>);
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+    impl Eq for Counted<'_> {}
+    impl PartialOrd for Counted<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Counted<'_> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.1.set(self.1.get() + 1);
+            self.0.cmp(&other.0)
+        }
+    }
+
+    // Taking the top `k` of `n` elements should cost `O(k log n)` comparisons
+    // beyond building the heap, not a full sort.
+    let n = 1024;
+    let k = 3;
+    let comparisons = Cell::new(0);
+    let data: Vec<_> = (0..n).map(|i| Counted((i * 7919) % n, &comparisons)).collect();
+
+    let heap = BinaryHeap::from(data);
+    comparisons.set(0);
+    let top: Vec<u32> = heap.into_iter_sorted().take(k as usize).map(|c| c.0).collect();
+    assert_eq!(top, [n - 1, n - 2, n - 3]);
+    assert!(comparisons.get() <= 2 * (k as usize) * 11, "{} comparisons", comparisons.get());
+
+    let mut heap = BinaryHeap::from((0..n).map(|i| Counted(i, &comparisons)).collect::<Vec<_>>());
+    comparisons.set(0);
+    let mut drain = heap.drain_sorted();
+    let top: Vec<u32> = drain.by_ref().take(k as usize).map(|c| c.0).collect();
+    assert_eq!(top, [n - 1, n - 2, n - 3]);
+    assert!(comparisons.get() <= 2 * (k as usize) * 11, "{} comparisons", comparisons.get());
+    drop(drain);
+    assert!(heap.is_empty());
+}
+
 fn check_exact_size_iterator<I: ExactSizeIterator>(len: usize, it: I) {
     let mut it = it;
 
@@ -474,6 +521,31 @@ fn test_retain() {
     assert!(a.is_empty());
 }
 
+#[test]
+fn test_retain_random() {
+    use rand::Rng;
+
+    let mut rng = crate::test_helpers::test_rng();
+    // Miri is too slow
+    let rounds = if cfg!(miri) { 2 } else { 50 };
+    for _ in 0..rounds {
+        let len = rng.gen_range(0..200);
+        let data: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+        let modulus = rng.gen_range(1..5);
+
+        let mut heap = BinaryHeap::from(data.clone());
+        heap.retain(|x| x % modulus != 0);
+
+        let mut expected: Vec<u32> = data.into_iter().filter(|x| x % modulus != 0).collect();
+        expected.sort();
+        assert_eq!(heap.len(), expected.len());
+        // Every parent must still be at least as large as its children.
+        let v = heap.as_slice();
+        assert!((1..v.len()).all(|i| v[(i - 1) / 2] >= v[i]));
+        assert_eq!(heap.into_sorted_vec(), expected);
+    }
+}
+
 #[test]
 fn test_retain_catch_unwind() {
     let mut heap = BinaryHeap::from(vec![3, 1, 2]);
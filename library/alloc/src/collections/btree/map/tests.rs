@@ -940,6 +940,25 @@ fn test_retain() {
     assert_eq!(map[&6], 60);
 }
 
+#[test]
+fn test_retain_height_2() {
+    let size = MIN_INSERTS_HEIGHT_2 * 2;
+    let mut map = BTreeMap::from_iter((0..size).map(|x| (x, x)));
+    assert_eq!(map.height(), Some(2));
+
+    // Values can be updated while deciding what to keep.
+    map.retain(|&k, v| {
+        *v *= 10;
+        k % 3 != 0
+    });
+    assert_eq!(map.len(), size - (size + 2) / 3);
+    assert!(map.iter().all(|(&k, &v)| k % 3 != 0 && v == k * 10));
+    map.check();
+
+    map.retain(|&k, _| k >= size - 2);
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), [(size - 2, (size - 2) * 10)]);
+}
+
 mod test_drain_filter {
     use super::*;
 
@@ -1356,6 +1375,46 @@ fn test_entry() {
     map.check();
 }
 
+#[test]
+fn test_entry_insert_entry() {
+    // Into an empty map, which has no root node yet.
+    let mut map = BTreeMap::new();
+    let entry = map.entry(1).insert_entry(10);
+    assert_eq!(entry.key(), &1);
+    assert_eq!(entry.get(), &10);
+    assert_eq!(map.len(), 1);
+    map.check();
+
+    // Into a growing map, so that some insertions split nodes and push a new
+    // root level, and the returned entry must still point at the new element.
+    for i in 2..200 {
+        let mut entry = map.entry(i).insert_entry(i * 10);
+        assert_eq!(entry.key(), &i);
+        assert_eq!(entry.get(), &(i * 10));
+        *entry.get_mut() += 1;
+        assert_eq!(map[&i], i * 10 + 1);
+        assert_eq!(map.len(), i);
+    }
+    map.check();
+
+    // Over an existing key, which replaces the value.
+    let entry = map.entry(100).insert_entry(0);
+    assert_eq!(entry.remove_entry(), (100, 0));
+    assert_eq!(map.len(), 198);
+    map.check();
+
+    match map.entry(100) {
+        Occupied(_) => unreachable!(),
+        Vacant(view) => {
+            let entry = view.insert_entry(1000);
+            assert_eq!(*entry.into_mut(), 1000);
+        }
+    }
+    assert_eq!(map[&100], 1000);
+    assert_eq!(map.len(), 199);
+    map.check();
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = BTreeMap::new();
@@ -1976,6 +2035,32 @@ fn test_pop_first_last() {
     assert_eq!(map.pop_last(), None);
 }
 
+#[test]
+fn test_pop_first_last_height_2() {
+    // Use the map as a double-ended priority queue, draining a tree that
+    // starts out with internal nodes from both ends.
+    let size = MIN_INSERTS_HEIGHT_2;
+    let mut map: BTreeMap<_, _> = (0..size).map(|i| (i, i * 10)).collect();
+    assert_eq!(map.height(), Some(2));
+
+    let (mut lo, mut hi) = (0, size);
+    while lo < hi {
+        assert_eq!(map.first_key_value(), Some((&lo, &(lo * 10))));
+        assert_eq!(map.last_key_value(), Some((&(hi - 1), &((hi - 1) * 10))));
+        if (lo + hi) % 3 == 0 {
+            hi -= 1;
+            assert_eq!(map.pop_last(), Some((hi, hi * 10)));
+        } else {
+            assert_eq!(map.pop_first(), Some((lo, lo * 10)));
+            lo += 1;
+        }
+        assert_eq!(map.len(), hi - lo);
+        map.check();
+    }
+    assert_eq!(map.first_key_value(), None);
+    assert_eq!(map.last_key_value(), None);
+}
+
 #[test]
 fn test_get_key_value() {
     let mut map = BTreeMap::new();
@@ -762,6 +762,53 @@ fn test_cursor_pop_front_back() {
     assert_eq!(c.index, 2);
 }
 
+#[test]
+fn test_cursor_mut_remove_as_list_and_split() {
+    let mut m: LinkedList<u32> = (0..8).collect();
+    let mut cursor = m.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+
+    // Move the node holding 2 to just after 5 without reallocating it.
+    let node = cursor.remove_current_as_list().unwrap();
+    let ptr = node.front().unwrap() as *const u32;
+    assert_eq!(node.len(), 1);
+    assert_eq!(cursor.current(), Some(&mut 3));
+    assert_eq!(cursor.index(), Some(2));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 5));
+    cursor.splice_after(node);
+    cursor.move_next();
+    assert_eq!(cursor.current().map(|x| x as *const u32), Some(ptr));
+    assert_eq!(cursor.index(), Some(5));
+    check_links(&m);
+    assert_eq!(m.iter().cloned().collect::<Vec<_>>(), &[0, 1, 3, 4, 5, 2, 6, 7]);
+
+    let mut cursor = m.cursor_front_mut();
+    cursor.move_prev();
+    assert!(cursor.remove_current_as_list().is_none());
+
+    // Splitting in the middle keeps the cursor on the same element.
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 3));
+    let front = cursor.split_before();
+    assert_eq!(cursor.current(), Some(&mut 3));
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_next();
+    let back = cursor.split_after();
+    assert_eq!(cursor.current(), Some(&mut 4));
+    assert_eq!(cursor.index(), Some(1));
+    check_links(&m);
+    check_links(&front);
+    check_links(&back);
+    assert_eq!(front.into_iter().collect::<Vec<_>>(), &[0, 1]);
+    assert_eq!(m.iter().cloned().collect::<Vec<_>>(), &[3, 4]);
+    assert_eq!(back.into_iter().collect::<Vec<_>>(), &[5, 2, 6, 7]);
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = LinkedList::new();
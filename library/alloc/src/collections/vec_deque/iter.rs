@@ -90,6 +90,11 @@ fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
         self.i2.try_fold(acc, &mut f)
     }
 
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
     #[inline]
     fn last(mut self) -> Option<&'a T> {
         self.next_back()
@@ -464,6 +464,39 @@ fn test_binary_search_key() {
     assert_eq!(tester.binary_search_by_key(&100, |&(_a, b)| b), Err(13));
 }
 
+#[test]
+fn test_binary_search_wrapped() {
+    // Build a sorted deque whose elements are split across the end of the buffer, so that the
+    // search has to look at both slices.
+    let mut tester = VecDeque::with_capacity(16);
+    for i in (0..6).rev() {
+        tester.push_front(i * 2);
+    }
+    for i in 6..10 {
+        tester.push_back(i * 2);
+    }
+    let (front, back) = tester.as_slices();
+    assert!(!front.is_empty() && !back.is_empty());
+    let split = front.len();
+
+    for (i, x) in (0..20).step_by(2).enumerate() {
+        assert_eq!(tester.binary_search(&x), Ok(i));
+        assert_eq!(tester.binary_search(&(x + 1)), Err(i + 1));
+        assert_eq!(tester.binary_search_by(|e| e.cmp(&x)), Ok(i));
+        assert_eq!(tester.binary_search_by_key(&(x / 2), |e| e / 2), Ok(i));
+        assert_eq!(tester.partition_point(|&e| e < x), i);
+    }
+    assert_eq!(tester.binary_search(&-1), Err(0));
+    assert_eq!(tester.partition_point(|_| true), tester.len());
+    assert_eq!(tester.partition_point(|_| false), 0);
+
+    // Right at the boundary between the two slices.
+    let first_back = tester[split];
+    assert_eq!(tester.binary_search(&first_back), Ok(split));
+    assert_eq!(tester.binary_search(&(first_back - 1)), Err(split));
+    assert_eq!(tester.partition_point(|&e| e < first_back), split);
+}
+
 #[test]
 fn make_contiguous_big_head() {
     let mut tester = VecDeque::with_capacity(15);
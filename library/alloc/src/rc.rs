@@ -2170,6 +2170,36 @@ fn to_rc_slice(self) -> Rc<[T]> {
     }
 }
 
+impl Rc<str> {
+    /// Collects an iterator of `char`s or string slices into an `Rc<str>`.
+    ///
+    /// This accepts the same items as [`String`]'s [`FromIterator`] impls.
+    /// The items are first collected into a `String`, since the length in
+    /// bytes isn't known up front, and then copied into the new allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(shared_from_iter_str)]
+    /// use std::rc::Rc;
+    ///
+    /// let shared = Rc::<str>::from_iter_str("statue".chars().rev());
+    /// assert_eq!("eutats", &shared[..]);
+    ///
+    /// let shared = Rc::<str>::from_iter_str(["foo", "bar", "baz"]);
+    /// assert_eq!("foobarbaz", &shared[..]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "shared_from_iter_str", issue = "none")]
+    pub fn from_iter_str<I>(iter: I) -> Rc<str>
+    where
+        I: IntoIterator,
+        String: FromIterator<I::Item>,
+    {
+        String::from_iter(iter).into()
+    }
+}
+
 /// `Weak` is a version of [`Rc`] that holds a non-owning reference to the
 /// managed allocation. The allocation is accessed by calling [`upgrade`] on the `Weak`
 /// pointer, which returns an <code>[Option]<[Rc]\<T>></code>.
@@ -231,6 +231,16 @@ pub fn sort(&mut self)
     /// assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0]);
     /// ```
     ///
+    /// If the slice may contain a `NaN`, [`f64::total_cmp`] provides a total order instead, with
+    /// positive `NaN`s sorted after every other value:
+    ///
+    /// ```
+    /// let mut floats = [5f64, f64::NAN, 1.0, -0.0, 0.0];
+    /// floats.sort_by(f64::total_cmp);
+    /// assert_eq!(&floats[..4], [-0.0, 0.0, 1.0, 5.0]);
+    /// assert!(floats[4].is_nan());
+    /// ```
+    ///
     /// When applicable, unstable sorting is preferred because it is generally faster than stable
     /// sorting and it doesn't allocate auxiliary memory.
     /// See [`sort_unstable_by`](slice::sort_unstable_by).
@@ -315,6 +315,22 @@ fn test_sort() {
     assert!(v == [0xDEADBEEF]);
 }
 
+#[test]
+fn test_sort_by_total_cmp() {
+    let mut v = [1.0f64, f64::NAN, -f64::NAN, -0.0, f64::INFINITY, 0.0, -2.5, f64::NEG_INFINITY];
+    v.sort_by(f64::total_cmp);
+    assert!(v[0].is_nan() && v[0].is_sign_negative());
+    assert_eq!(&v[1..7], [f64::NEG_INFINITY, -2.5, -0.0, 0.0, 1.0, f64::INFINITY]);
+    assert!(v[3].is_sign_negative() && v[4].is_sign_positive());
+    assert!(v[7].is_nan() && v[7].is_sign_positive());
+
+    let mut w = [3.0f32, f32::NAN, -1.0, 0.0, -0.0];
+    w.sort_unstable_by(f32::total_cmp);
+    assert_eq!(&w[..4], [-1.0, -0.0, 0.0, 3.0]);
+    assert!(w[1].is_sign_negative() && w[2].is_sign_positive());
+    assert!(w[4].is_nan());
+}
+
 #[test]
 fn test_sort_stability() {
     // Miri is too slow
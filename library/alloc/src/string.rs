@@ -1347,14 +1347,18 @@ pub fn remove(&mut self, idx: usize) -> char {
         ch
     }
 
-    /// Remove all matches of pattern `pat` in the `String`.
+    /// Remove all matches of pattern `pat` in the `String`, returning how many
+    /// were removed.
+    ///
+    /// This works in place: the retained parts are shifted down over the
+    /// matches and nothing is reallocated.
     ///
     /// # Examples
     ///
     /// ```
     /// #![feature(string_remove_matches)]
     /// let mut s = String::from("Trees are not green, the sky is not blue.");
-    /// s.remove_matches("not ");
+    /// assert_eq!(s.remove_matches("not "), 2);
     /// assert_eq!("Trees are green, the sky is blue.", s);
     /// ```
     ///
@@ -1369,13 +1373,13 @@ pub fn remove(&mut self, idx: usize) -> char {
     /// ```
     #[cfg(not(no_global_oom_handling))]
     #[unstable(feature = "string_remove_matches", reason = "new API", issue = "72826")]
-    pub fn remove_matches<'a, P>(&'a mut self, pat: P)
+    pub fn remove_matches<'a, P>(&'a mut self, pat: P) -> usize
     where
         P: for<'x> Pattern<'x>,
     {
         use core::str::pattern::Searcher;
 
-        let rejections = {
+        let (matches, rejections) = {
             let mut searcher = pat.into_searcher(self);
             // Per Searcher::next:
             //
@@ -1394,7 +1398,7 @@ pub fn remove_matches<'a, P>(&'a mut self, pat: P)
                 Some((prev_front, start))
             })
             .collect();
-            rejections.into_iter().chain(core::iter::once((front, self.len())))
+            (rejections.len(), rejections.into_iter().chain(core::iter::once((front, self.len()))))
         };
 
         let mut len = 0;
@@ -1419,6 +1423,7 @@ pub fn remove_matches<'a, P>(&'a mut self, pat: P)
         unsafe {
             self.vec.set_len(len);
         }
+        matches
     }
 
     /// Retains only the characters specified by the predicate.
@@ -2833,6 +2838,30 @@ fn from_iter<I: IntoIterator<Item = String>>(it: I) -> Cow<'a, str> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
+#[stable(feature = "cow_str_from_iter_cow", since = "CURRENT_RUSTC_VERSION")]
+impl<'a> FromIterator<Cow<'a, str>> for Cow<'a, str> {
+    fn from_iter<I: IntoIterator<Item = Cow<'a, str>>>(it: I) -> Cow<'a, str> {
+        let mut iterator = it.into_iter();
+
+        // A single item is passed through as is, so collecting one borrowed
+        // piece doesn't allocate. Otherwise the first item is reused as the
+        // buffer, like in `FromIterator<Cow<'a, str>> for String`.
+        match iterator.next() {
+            None => Cow::Borrowed(""),
+            Some(first) => match iterator.next() {
+                None => first,
+                Some(second) => {
+                    let mut buf = first.into_owned();
+                    buf.push_str(&second);
+                    buf.extend(iterator);
+                    Cow::Owned(buf)
+                }
+            },
+        }
+    }
+}
+
 #[stable(feature = "from_string_for_vec_u8", since = "1.14.0")]
 impl From<String> for Vec<u8> {
     /// Converts the given [`String`] to a vector [`Vec`] that holds values of type [`u8`].
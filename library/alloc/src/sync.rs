@@ -2898,6 +2898,36 @@ fn to_arc_slice(self) -> Arc<[T]> {
     }
 }
 
+impl Arc<str> {
+    /// Collects an iterator of `char`s or string slices into an `Arc<str>`.
+    ///
+    /// This accepts the same items as [`String`]'s [`FromIterator`] impls.
+    /// The items are first collected into a `String`, since the length in
+    /// bytes isn't known up front, and then copied into the new allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(shared_from_iter_str)]
+    /// use std::sync::Arc;
+    ///
+    /// let shared = Arc::<str>::from_iter_str("statue".chars().rev());
+    /// assert_eq!("eutats", &shared[..]);
+    ///
+    /// let shared = Arc::<str>::from_iter_str(["foo", "bar", "baz"]);
+    /// assert_eq!("foobarbaz", &shared[..]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "shared_from_iter_str", issue = "none")]
+    pub fn from_iter_str<I>(iter: I) -> Arc<str>
+    where
+        I: IntoIterator,
+        String: FromIterator<I::Item>,
+    {
+        String::from_iter(iter).into()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> borrow::Borrow<T> for Arc<T> {
     fn borrow(&self) -> &T {
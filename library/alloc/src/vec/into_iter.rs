@@ -236,6 +236,11 @@ fn count(self) -> usize {
         self.len()
     }
 
+    #[inline]
+    fn last(mut self) -> Option<T> {
+        self.next_back()
+    }
+
     #[inline]
     fn next_chunk<const N: usize>(&mut self) -> Result<[T; N], core::array::IntoIter<T, N>> {
         let mut raw_ary = MaybeUninit::uninit_array();
@@ -2933,6 +2933,9 @@ pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoI
     /// Note that `drain_filter` also lets you mutate every element in the filter closure,
     /// regardless of whether you choose to keep or remove it.
     ///
+    /// If the returned `DrainFilter` goes out of scope without being dropped (due to
+    /// [`mem::forget`], for example), the vector may have lost and leaked elements arbitrarily.
+    ///
     /// # Examples
     ///
     /// Splitting an array into evens and odds, reusing the original allocation:
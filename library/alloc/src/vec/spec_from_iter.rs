@@ -16,10 +16,17 @@
 /// +-+-------------------------------+  +---------------------+
 /// |SpecFromIter                  +---->+SpecFromIterNested   |
 /// |where I:                      |  |  |where I:             |
-/// |  Iterator (default)----------+  |  |  Iterator (default) |
-/// |  vec::IntoIter               |  |  |  TrustedLen         |
-/// |  SourceIterMarker---fallback-+  |  +---------------------+
-/// +---------------------------------+
+/// |  Iterator (default)----------+  |  |  Iterator (default)-+--+
+/// |  vec::IntoIter               |  |  |  TrustedLen         |  |
+/// |  SourceIterMarker---fallback-+  |  +---------------------+  |
+/// +---------------------------------+                           |
+///                                                               v
+///                                 +-----------------------------+-+
+///                                 |SpecFromIterFallback           |
+///                                 |where I:                       |
+///                                 |  Iterator (default)           |
+///                                 |  GenericShunt<TrustedLen, _>  |
+///                                 +-------------------------------+
 /// ```
 pub(super) trait SpecFromIter<T, I> {
     fn from_iter(iter: I) -> Self;
@@ -1,5 +1,5 @@
 use core::cmp;
-use core::iter::TrustedLen;
+use core::iter::{GenericShunt, TrustedLen};
 use core::ptr;
 
 use crate::raw_vec::RawVec;
@@ -14,6 +14,41 @@ pub(super) trait SpecFromIterNested<T, I> {
 }
 
 impl<T, I> SpecFromIterNested<T, I> for Vec<T>
+where
+    I: Iterator<Item = T>,
+{
+    default fn from_iter(iterator: I) -> Self {
+        SpecFromIterFallback::from_iter(iterator)
+    }
+}
+
+impl<T, I> SpecFromIterNested<T, I> for Vec<T>
+where
+    I: TrustedLen<Item = T>,
+{
+    fn from_iter(iterator: I) -> Self {
+        let mut vector = match iterator.size_hint() {
+            (_, Some(upper)) => Vec::with_capacity(upper),
+            // TrustedLen contract guarantees that `size_hint() == (_, None)` means that there
+            // are more than `usize::MAX` elements.
+            // Since the previous branch would eagerly panic if the capacity is too large
+            // (via `with_capacity`) we do the same here.
+            _ => panic!("capacity overflow"),
+        };
+        // reuse extend specialization for TrustedLen
+        vector.spec_extend(iterator);
+        vector
+    }
+}
+
+/// Yet another specialization trait for Vec::from_iter, used for iterators
+/// that are not `TrustedLen` themselves.
+/// see [`SpecFromIter`](super::SpecFromIter) for details.
+trait SpecFromIterFallback<T, I> {
+    fn from_iter(iter: I) -> Self;
+}
+
+impl<T, I> SpecFromIterFallback<T, I> for Vec<T>
 where
     I: Iterator<Item = T>,
 {
@@ -45,20 +80,36 @@ impl<T, I> SpecFromIterNested<T, I> for Vec<T>
     }
 }
 
-impl<T, I> SpecFromIterNested<T, I> for Vec<T>
+/// Specialization marker for the sources of a [`GenericShunt`] whose upper
+/// bound is exact.
+#[rustc_unsafe_specialization_marker]
+trait TrustedLenMarker {}
+
+impl<T> TrustedLenMarker for T where T: TrustedLen {}
+
+impl<'a, T, I, R> SpecFromIterFallback<T, GenericShunt<'a, I, R>> for Vec<T>
 where
-    I: TrustedLen<Item = T>,
+    GenericShunt<'a, I, R>: Iterator<Item = T>,
+    I: TrustedLenMarker,
 {
-    fn from_iter(iterator: I) -> Self {
-        let mut vector = match iterator.size_hint() {
-            (_, Some(upper)) => Vec::with_capacity(upper),
-            // TrustedLen contract guarantees that `size_hint() == (_, None)` means that there
-            // are more than `usize::MAX` elements.
-            // Since the previous branch would eagerly panic if the capacity is too large
-            // (via `with_capacity`) we do the same here.
-            _ => panic!("capacity overflow"),
+    fn from_iter(mut iterator: GenericShunt<'a, I, R>) -> Self {
+        // This is what `collect::<Result<Vec<_>, _>>()` and friends end up
+        // calling. The shunt may stop at the first residual, so its lower bound
+        // is 0, but in that case the vector is thrown away anyway. The only
+        // vector that is kept holds every item of the `TrustedLen` source,
+        // so reserve the exact upper bound once. Pull the first item before
+        // that, so a source that fails right away doesn't allocate at all, and
+        // don't panic if reserving fails: a residual may well show up long
+        // before a huge bound is hit.
+        let first = match iterator.next() {
+            None => return Vec::new(),
+            Some(element) => element,
         };
-        // reuse extend specialization for TrustedLen
+        let mut vector = Vec::new();
+        if let (_, Some(upper)) = iterator.size_hint() {
+            let _ = vector.try_reserve_exact(upper.saturating_add(1));
+        }
+        vector.push(first);
         vector.spec_extend(iterator);
         vector
     }
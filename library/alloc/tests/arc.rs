@@ -196,6 +196,19 @@ fn next(&mut self) -> Option<Self::Item> {
     assert_eq!(&[Box::new(42), Box::new(24)], &*iter.collect::<Rc<[_]>>());
 }
 
+#[test]
+fn shared_from_iter_str() {
+    let chars = "α-β-γ".chars().filter(|&c| c != '-');
+    assert_eq!(&*Arc::<str>::from_iter_str(chars), "αβγ");
+
+    assert_eq!(&*Arc::<str>::from_iter_str(["foo", "", "bar"]), "foobar");
+
+    assert_eq!(&*Arc::<str>::from_iter_str(std::iter::empty::<&str>()), "");
+
+    // Collecting into `Arc<_>` must still infer `Arc<[T]>`.
+    assert_eq!(&*"ab".chars().collect::<Arc<_>>(), ['a', 'b']);
+}
+
 #[test]
 fn weak_may_dangle() {
     fn hmm<'a>(val: &'a mut Weak<&'a str>) -> Weak<&'a str> {
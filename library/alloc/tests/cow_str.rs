@@ -142,3 +142,30 @@ fn check_cow_clone_from() {
     c3.clone_from(&c2);
     assert_eq!(c2, c3);
 }
+
+#[test]
+fn check_cow_from_iter_cow() {
+    let empty: Cow<'_, str> = std::iter::empty::<Cow<'_, str>>().collect();
+    assert_eq!("", empty);
+
+    let single: Cow<'_, str> = [Cow::Borrowed("Hello")].into_iter().collect();
+    assert!(matches!(single, Cow::Borrowed("Hello")));
+
+    let pieces = [Cow::Borrowed("Hello"), Cow::Owned(", ".to_string()), Cow::Borrowed("World!")];
+    let joined: Cow<'_, str> = pieces.into_iter().collect();
+    assert_eq!("Hello, World!", joined);
+    assert!(matches!(joined, Cow::Owned(_)));
+}
+
+#[test]
+fn check_cow_from_iter_cow_stops_at_first_none() {
+    // A non-fused iterator must not be polled again once it returned `None`.
+    let mut calls = 0;
+    let empty: Cow<'_, str> = std::iter::from_fn(|| {
+        calls += 1;
+        if calls == 1 { None } else { Some(Cow::Borrowed("unexpected")) }
+    })
+    .collect();
+    assert_eq!("", empty);
+    assert_eq!(calls, 1);
+}
@@ -1218,6 +1218,33 @@ fn test_ends_with() {
     assert!(b"foobar".ends_with(empty));
 }
 
+#[test]
+fn test_strip_prefix() {
+    assert_eq!(b"foobar".strip_prefix(b"foo"), Some(&b"bar"[..]));
+    assert_eq!(b"foobar".strip_prefix(b"foobar"), Some(&b""[..]));
+    assert_eq!(b"foobar".strip_prefix(b"oob"), None);
+    assert_eq!(b"foo".strip_prefix(b"foobar"), None);
+    let empty: &[u8] = &[];
+    assert_eq!(empty.strip_prefix(empty), Some(empty));
+    assert_eq!(empty.strip_prefix(b"foo"), None);
+    assert_eq!(b"foobar".strip_prefix(empty), Some(&b"foobar"[..]));
+    // Arrays work as patterns too.
+    assert_eq!([1, 2, 3].strip_prefix(&[1]), Some(&[2, 3][..]));
+}
+
+#[test]
+fn test_strip_suffix() {
+    assert_eq!(b"foobar".strip_suffix(b"bar"), Some(&b"foo"[..]));
+    assert_eq!(b"foobar".strip_suffix(b"foobar"), Some(&b""[..]));
+    assert_eq!(b"foobar".strip_suffix(b"oba"), None);
+    assert_eq!(b"bar".strip_suffix(b"foobar"), None);
+    let empty: &[u8] = &[];
+    assert_eq!(empty.strip_suffix(empty), Some(empty));
+    assert_eq!(empty.strip_suffix(b"foo"), None);
+    assert_eq!(b"foobar".strip_suffix(empty), Some(&b"foobar"[..]));
+    assert_eq!([1, 2, 3].strip_suffix(&[2, 3]), Some(&[1][..]));
+}
+
 #[test]
 fn test_mut_splitator() {
     let mut xs = [0, 1, 0, 2, 3, 0, 0, 4, 5, 0];
@@ -1669,3 +1696,32 @@ fn test_group_by_mut() {
     assert_eq!(iter.next(), Some(&mut [2, 2, 2][..]));
     assert_eq!(iter.next_back(), None);
 }
+
+#[test]
+fn test_group_by_non_equality_predicate() {
+    // Group ascending runs.
+    let slice = &[1, 2, 3, 2, 2, 5, 0, 1];
+    let runs: Vec<&[i32]> = slice.group_by(|a, b| a <= b).collect();
+    assert_eq!(runs, [&[1, 2, 3][..], &[2, 2, 5][..], &[0, 1][..]]);
+    let runs: Vec<&[i32]> = slice.group_by(|a, b| a <= b).rev().collect();
+    assert_eq!(runs, [&[0, 1][..], &[2, 2, 5][..], &[1, 2, 3][..]]);
+
+    // The predicate is only ever called on neighbouring elements.
+    let slice = &[1, 2, 3, 5, 6, 8];
+    let mut iter = slice.group_by(|a, b| b - a == 1);
+    assert_eq!(iter.size_hint(), (1, Some(6)));
+    assert_eq!(iter.next(), Some(&[1, 2, 3][..]));
+    assert_eq!(iter.last(), Some(&[8][..]));
+
+    let empty: &[i32] = &[];
+    let mut iter = empty.group_by(|_, _| unreachable!());
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let slice = &mut [1, 2, 3, 2, 2, 5, 0, 1];
+    for run in slice.group_by_mut(|a, b| a <= b) {
+        run.reverse();
+    }
+    assert_eq!(slice, &[3, 2, 1, 5, 2, 2, 1, 0]);
+}
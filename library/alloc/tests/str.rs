@@ -241,6 +241,37 @@ fn test_ends_with() {
     assert!("ddö".ends_with("dö"));
 }
 
+#[test]
+fn test_strip_prefix() {
+    assert_eq!("".strip_prefix(""), Some(""));
+    assert_eq!("abc".strip_prefix(""), Some("abc"));
+    assert_eq!("abc".strip_prefix("a"), Some("bc"));
+    assert_eq!("abc".strip_prefix("abc"), Some(""));
+    assert_eq!("a".strip_prefix("abc"), None);
+    assert_eq!("ödd".strip_prefix("-"), None);
+    assert_eq!("ödd".strip_prefix("ö"), Some("dd"));
+    assert_eq!("ödd".strip_prefix('ö'), Some("dd"));
+    assert_eq!("ödd".strip_prefix(['x', 'ö']), Some("dd"));
+    assert_eq!("1abc".strip_prefix(char::is_numeric), Some("abc"));
+    // Only a single match is removed, unlike `trim_start_matches`.
+    assert_eq!("aab".strip_prefix('a'), Some("ab"));
+}
+
+#[test]
+fn test_strip_suffix() {
+    assert_eq!("".strip_suffix(""), Some(""));
+    assert_eq!("abc".strip_suffix(""), Some("abc"));
+    assert_eq!("abc".strip_suffix("c"), Some("ab"));
+    assert_eq!("abc".strip_suffix("abc"), Some(""));
+    assert_eq!("a".strip_suffix("abc"), None);
+    assert_eq!("ddö".strip_suffix("-"), None);
+    assert_eq!("ddö".strip_suffix("dö"), Some("d"));
+    assert_eq!("ddö".strip_suffix('ö'), Some("dd"));
+    assert_eq!("ddö".strip_suffix(&['x', 'ö'][..]), Some("dd"));
+    assert_eq!("abc1".strip_suffix(|c: char| c.is_ascii_digit()), Some("abc"));
+    assert_eq!("abb".strip_suffix('b'), Some("ab"));
+}
+
 #[test]
 fn test_is_empty() {
     assert!("".is_empty());
@@ -1438,6 +1469,31 @@ fn test_split_char_iterator_inclusive_rev() {
     assert_eq!(split, ["CaT", "TurtlE", "SharK", "SheeP"]);
 }
 
+#[test]
+fn test_split_str_iterator_inclusive() {
+    let data = "first\r\nsecond\r\n\r\nlast";
+    let split: Vec<&str> = data.split_inclusive("\r\n").collect();
+    assert_eq!(split, ["first\r\n", "second\r\n", "\r\n", "last"]);
+    assert_eq!(split.concat(), data);
+
+    let split: Vec<&str> = data.split_inclusive("\r\n").rev().collect();
+    assert_eq!(split, ["last", "\r\n", "second\r\n", "first\r\n"]);
+
+    // `char` patterns search from both ends at once, so the two directions can be mixed.
+    let mut split = data.split_inclusive('\n');
+    assert_eq!(split.next(), Some("first\r\n"));
+    assert_eq!(split.next_back(), Some("last"));
+    assert_eq!(split.next_back(), Some("\r\n"));
+    assert_eq!(split.next(), Some("second\r\n"));
+    assert_eq!(split.next(), None);
+    assert_eq!(split.next_back(), None);
+
+    // No trailing empty segment after a final terminator, and nothing at all
+    // for an empty haystack.
+    assert_eq!("a\n".split_inclusive('\n').collect::<Vec<_>>(), ["a\n"]);
+    assert_eq!("".split_inclusive('\n').next(), None);
+}
+
 #[test]
 fn test_rsplit() {
     let data = "\nMäry häd ä little lämb\nLittle lämb\n";
@@ -1490,6 +1546,21 @@ fn test_rsplit_once() {
     assert_eq!("---".rsplit_once("--"), Some(("-", "")));
 }
 
+#[test]
+fn test_split_once_patterns() {
+    assert_eq!("key=value=x".split_once('='), Some(("key", "value=x")));
+    assert_eq!("key=value=x".rsplit_once('='), Some(("key=value", "x")));
+    assert_eq!("a1b2c".split_once(char::is_numeric), Some(("a", "b2c")));
+    assert_eq!("a1b2c".rsplit_once(|c: char| c.is_ascii_digit()), Some(("a1b", "c")));
+    assert_eq!("a, b; c".split_once(&[',', ';'][..]), Some(("a", " b; c")));
+    assert_eq!("a, b; c".rsplit_once([',', ';']), Some(("a, b", " c")));
+    // Multi-byte delimiters split on character boundaries.
+    assert_eq!("Märy→häd→ä".split_once('→'), Some(("Märy", "häd→ä")));
+    assert_eq!("Märy→häd→ä".rsplit_once('→'), Some(("Märy→häd", "ä")));
+    assert_eq!("no delimiter".split_once(':'), None);
+    assert_eq!("no delimiter".rsplit_once(':'), None);
+}
+
 #[test]
 fn test_split_whitespace() {
     let data = "\n \tMäry   häd\tä  little lämb\nLittle lämb\n";
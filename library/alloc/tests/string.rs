@@ -393,6 +393,29 @@ fn test_remove_matches() {
     assert_eq!(s, "");
 }
 
+#[test]
+fn test_remove_matches_count() {
+    let mut s = "a,b,,c".to_string();
+    let cap = s.capacity();
+    assert_eq!(s.remove_matches(','), 3);
+    assert_eq!(s, "abc");
+    assert_eq!(s.capacity(), cap);
+    assert_eq!(s.remove_matches(','), 0);
+
+    let mut s = "banana".to_string();
+    assert_eq!(s.remove_matches("ana"), 1);
+    assert_eq!(s, "bna");
+
+    let mut s = "foo bar baz".to_string();
+    assert_eq!(s.remove_matches(char::is_whitespace), 2);
+    assert_eq!(s, "foobarbaz");
+
+    // The empty pattern matches between every character and at both ends.
+    let mut s = "ab".to_string();
+    assert_eq!(s.remove_matches(""), 3);
+    assert_eq!(s, "ab");
+}
+
 #[test]
 fn test_retain() {
     let mut s = String::from("α_β_γ");
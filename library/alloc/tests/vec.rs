@@ -297,6 +297,34 @@ fn test_retain() {
     assert_eq!(vec, [2, 4]);
 }
 
+#[test]
+fn test_retain_mut() {
+    // Decrement each time-to-live and drop the entries that expire.
+    let mut ttls = vec![("a", 1), ("b", 3), ("c", 2), ("d", 1)];
+    ttls.retain_mut(|(_, ttl)| {
+        *ttl -= 1;
+        *ttl > 0
+    });
+    assert_eq!(ttls, [("b", 2), ("c", 1)]);
+}
+
+#[test]
+fn test_retain_mut_pred_panic() {
+    let v = (0..5).map(Rc::new).collect::<Vec<_>>();
+    let mut w = v.clone();
+    catch_unwind(AssertUnwindSafe(|| {
+        w.retain_mut(|r| match **r {
+            0 | 2 => true,
+            1 => false,
+            _ => panic!(),
+        });
+    }))
+    .unwrap_err();
+    // The rejected element is dropped, and the unvisited tail is kept.
+    assert_eq!(w.iter().map(|r| **r).collect::<Vec<_>>(), [0, 2, 3, 4]);
+    assert_eq!(Rc::strong_count(&v[1]), 1);
+}
+
 #[test]
 fn test_retain_predicate_order() {
     for to_keep in [true, false] {
@@ -519,6 +547,20 @@ fn test_zip_unzip() {
     assert_eq!((3, 6), (left[2], right[2]));
 }
 
+// `vec::IntoIter` is only `TrustedRandomAccessNoCoerce`, which `Zip` handles
+// separately from full `TrustedRandomAccess`.
+#[test]
+fn test_zip_into_iter_is_empty() {
+    let mut it = vec![1, 2].into_iter().zip(vec![3, 4, 5]);
+    assert!(!it.is_empty());
+    assert_eq!(it.next(), Some((1, 3)));
+    assert!(!it.is_empty());
+    assert_eq!(it.next(), Some((2, 4)));
+    assert!(it.is_empty());
+
+    assert!(vec![1, 2].into_iter().zip(Vec::<i32>::new()).is_empty());
+}
+
 #[test]
 fn test_cmp() {
     let x: &[isize] = &[1, 2, 3, 4, 5];
@@ -1010,6 +1052,16 @@ fn test_into_iter_count() {
     assert_eq!([1, 2, 3].into_iter().count(), 3);
 }
 
+#[test]
+fn test_into_iter_last() {
+    assert_eq!(vec![1, 2, 3].into_iter().last(), Some(3));
+    assert_eq!(Vec::<i32>::new().into_iter().last(), None);
+
+    let mut it = vec![String::from("a"), String::from("b")].into_iter();
+    it.next_back();
+    assert_eq!(it.last().as_deref(), Some("a"));
+}
+
 #[test]
 fn test_into_iter_next_chunk() {
     let mut iter = b"lorem".to_vec().into_iter();
@@ -1179,6 +1231,21 @@ fn assert_in_place_trait<T: InPlaceIterable>(_: &T) {}
     assert_eq!(srcptr, sinkptr as *const usize);
 }
 
+#[test]
+fn test_from_iter_specialization_map_filter() {
+    let src: Vec<u32> = (0..64).collect();
+    let srcptr = src.as_ptr();
+    let sink = src
+        .into_iter()
+        .map(|x| x as i32 - 32)
+        .filter(|x| x % 3 == 0)
+        .filter_map(|x| x.checked_neg())
+        .collect::<Vec<i32>>();
+    assert_eq!(sink.as_ptr() as *const u32, srcptr);
+    assert_eq!(sink.capacity(), 64);
+    assert_eq!(sink, (-31..=32).rev().filter(|x| x % 3 == 0).collect::<Vec<_>>());
+}
+
 #[test]
 fn test_from_iter_specialization_head_tail_drop() {
     let drop_count: Vec<_> = (0..=2).map(|_| Rc::new(())).collect();
@@ -1280,6 +1347,32 @@ fn test_collect_after_iterator_clone() {
     assert_eq!(v, [1, 1, 1, 1, 1]);
     assert!(v.len() <= v.capacity());
 }
+
+#[test]
+fn test_collect_result() {
+    let v = (0..100).map(Ok::<_, ()>).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(v, (0..100).collect::<Vec<_>>());
+    // `TrustedLen` sources are collected with a single allocation.
+    assert_eq!(v.capacity(), 100);
+
+    let v = (0..100).map(Some).collect::<Option<Vec<_>>>().unwrap();
+    assert_eq!(v.capacity(), 100);
+
+    let r = (0..100).map(|i| if i < 50 { Ok(i) } else { Err(i) }).collect::<Result<Vec<_>, _>>();
+    assert_eq!(r, Err(50));
+
+    // The shunt may stop at the first `Err`, so it must not report the inner
+    // lower bound, or unbounded iterators would try to reserve `usize::MAX`.
+    let r = (0..).map(|i| if i < 3 { Ok(i) } else { Err(i) }).collect::<Result<Vec<_>, _>>();
+    assert_eq!(r, Err(3));
+
+    // Nor may the upper bound of the source make the collect panic up front.
+    let r = (0..usize::MAX)
+        .map(|i| if i < 3 { Ok(i) } else { Err(i) })
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(r, Err(3));
+}
+
 #[test]
 fn test_cow_from() {
     let borrowed: &[_] = &["borrowed", "(slice)"];
@@ -1602,6 +1695,21 @@ fn drain_filter_unconsumed() {
     assert_eq!(vec, [2, 4]);
 }
 
+#[test]
+fn drain_filter_forget() {
+    // Leaking the iterator part-way must not expose moved-out or duplicated
+    // elements; the vector is left empty instead.
+    let mut vec = vec![1, 2, 3, 4, 5];
+    let mut drain = vec.drain_filter(|&mut x| x % 2 != 0);
+    assert_eq!(drain.next(), Some(1));
+    std::mem::forget(drain);
+    assert!(vec.is_empty());
+
+    // The vector remains usable afterwards.
+    vec.push(6);
+    assert_eq!(vec, [6]);
+}
+
 #[test]
 fn test_drain_filter_keep_rest() {
     let mut v = vec![0, 1, 2, 3, 4, 5, 6];
@@ -277,6 +277,9 @@ fn test_iter() {
         let b: &[_] = &[&8, &7, &6, &0, &1, &2, &3, &4];
         assert_eq!(d.iter().collect::<Vec<_>>(), b);
     }
+    assert_eq!(d.iter().count(), 8);
+    assert_eq!(d.iter_mut().count(), 8);
+    assert_eq!(d.iter().last(), Some(&4));
 
     let mut it = d.iter();
     let mut len = d.len();
@@ -1091,6 +1094,20 @@ fn test_retain() {
     assert_eq!(&v[..], &[2, 4]);
 }
 
+#[test]
+fn test_retain_mut() {
+    let mut buf = VecDeque::new();
+    buf.extend(1..5);
+    buf.rotate_left(2);
+    // Pushing to the front of a fresh deque wraps it around the buffer's end.
+    buf.push_front(0);
+    buf.retain_mut(|x| {
+        *x *= 10;
+        *x % 20 != 0
+    });
+    assert_eq!(buf, [30, 10]);
+}
+
 #[test]
 fn test_extend_ref() {
     let mut v = VecDeque::new();
@@ -1501,6 +1518,30 @@ fn test_rotate_right_random() {
     }
 }
 
+#[test]
+fn test_make_contiguous_sort() {
+    // Cover every head position (and so every way of wrapping around) of a
+    // small buffer, for every length that fits.
+    let cap = 8;
+    for len in 0..=cap {
+        for offset in 0..cap {
+            let mut dq = VecDeque::with_capacity(cap);
+            for _ in 0..offset {
+                dq.push_back(0);
+                dq.pop_front();
+            }
+            dq.extend((0..len).rev());
+
+            let slice = dq.make_contiguous();
+            slice.sort();
+            let expected = if len == 0 { Err(0) } else { Ok(len / 2) };
+            assert_eq!(slice.binary_search(&(len / 2)), expected);
+            assert!(dq.as_slices().1.is_empty());
+            assert!(dq.iter().copied().eq(0..len));
+        }
+    }
+}
+
 #[test]
 fn test_try_fold_empty() {
     assert_eq!(Some(0), VecDeque::<u32>::new().iter().try_fold(0, |_, _| None));
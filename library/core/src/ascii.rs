@@ -6,6 +6,9 @@
 //!
 //! The [`escape_default`] function provides an iterator over the bytes of an
 //! escaped version of the character given.
+//!
+//! The [`Char`] type represents a single character that is known to be ASCII,
+//! so that slices of it can be used as `str` without validation.
 
 #![stable(feature = "core_ascii", since = "1.26.0")]
 
@@ -14,6 +17,10 @@
 use crate::ops::Range;
 use crate::str::from_utf8_unchecked;
 
+mod ascii_char;
+#[unstable(feature = "ascii_char", issue = "none")]
+pub use ascii_char::AsciiChar as Char;
+
 /// An iterator over the escaped version of a byte.
 ///
 /// This `struct` is created by the [`escape_default`] function. See its
@@ -0,0 +1,570 @@
+//! Defines [`AsciiChar`], an enum with one variant for each ASCII character.
+
+use crate::fmt;
+use crate::mem::transmute;
+
+/// One of the 128 Unicode characters from U+0000 through U+007F,
+/// often known as the [ASCII] subset.
+///
+/// Officially, this is the first [block] in Unicode, _Basic Latin_.
+/// For details, see the [*C0 Controls and Basic Latin*][chart] code chart.
+///
+/// This block was based on older 7-bit character code standards such as
+/// ANSI X3.4-1977, ISO 646-1973, and [NIST FIPS 1-2].
+///
+/// Because every value of this type is a valid single-byte UTF-8 sequence,
+/// slices of it can be viewed as `&str` without any validation, and it can be
+/// converted to both [`u8`] and [`char`] infallibly.
+///
+/// # When to use this
+///
+/// The main advantage of this subset is that it's always valid UTF-8. As such,
+/// the `&[ascii::Char]` -> `&str` conversion function (as well as other related
+/// ones) are O(1): *no* runtime checks are needed.
+///
+/// If you're consuming strings, you should usually handle Unicode and thus
+/// accept `str`s, not limit yourself to `ascii::Char`s.
+///
+/// However, certain formats are intentionally designed to produce ASCII-only
+/// output in order to be 8-bit-clean. In those cases, it can be simpler and
+/// faster to generate `ascii::Char`s instead of dealing with the variable width
+/// properties of general UTF-8 encoded strings, while still allowing the result
+/// to be used freely with other Rust things that deal in general `str`s.
+///
+/// [ASCII]: https://www.unicode.org/glossary/index.html#ASCII
+/// [block]: https://www.unicode.org/glossary/index.html#block
+/// [chart]: https://www.unicode.org/charts/PDF/U0000.pdf
+/// [NIST FIPS 1-2]: https://nvlpubs.nist.gov/nistpubs/Legacy/FIPS/fipspub1-2-1977.pdf
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[unstable(feature = "ascii_char", issue = "none")]
+#[repr(u8)]
+pub enum AsciiChar {
+    /// U+0000
+    Null = 0,
+    /// U+0001
+    StartOfHeading = 1,
+    /// U+0002
+    StartOfText = 2,
+    /// U+0003
+    EndOfText = 3,
+    /// U+0004
+    EndOfTransmission = 4,
+    /// U+0005
+    Enquiry = 5,
+    /// U+0006
+    Acknowledge = 6,
+    /// U+0007
+    Bell = 7,
+    /// U+0008
+    Backspace = 8,
+    /// U+0009
+    CharacterTabulation = 9,
+    /// U+000A
+    LineFeed = 10,
+    /// U+000B
+    LineTabulation = 11,
+    /// U+000C
+    FormFeed = 12,
+    /// U+000D
+    CarriageReturn = 13,
+    /// U+000E
+    ShiftOut = 14,
+    /// U+000F
+    ShiftIn = 15,
+    /// U+0010
+    DataLinkEscape = 16,
+    /// U+0011
+    DeviceControlOne = 17,
+    /// U+0012
+    DeviceControlTwo = 18,
+    /// U+0013
+    DeviceControlThree = 19,
+    /// U+0014
+    DeviceControlFour = 20,
+    /// U+0015
+    NegativeAcknowledge = 21,
+    /// U+0016
+    SynchronousIdle = 22,
+    /// U+0017
+    EndOfTransmissionBlock = 23,
+    /// U+0018
+    Cancel = 24,
+    /// U+0019
+    EndOfMedium = 25,
+    /// U+001A
+    Substitute = 26,
+    /// U+001B
+    Escape = 27,
+    /// U+001C
+    InformationSeparatorFour = 28,
+    /// U+001D
+    InformationSeparatorThree = 29,
+    /// U+001E
+    InformationSeparatorTwo = 30,
+    /// U+001F
+    InformationSeparatorOne = 31,
+    /// U+0020
+    Space = 32,
+    /// U+0021 (`!`)
+    ExclamationMark = 33,
+    /// U+0022 (`"`)
+    QuotationMark = 34,
+    /// U+0023 (`#`)
+    NumberSign = 35,
+    /// U+0024 (`$`)
+    Dollar = 36,
+    /// U+0025 (`%`)
+    PercentSign = 37,
+    /// U+0026 (`&`)
+    Ampersand = 38,
+    /// U+0027 (`'`)
+    Apostrophe = 39,
+    /// U+0028 (`(`)
+    LeftParenthesis = 40,
+    /// U+0029 (`)`)
+    RightParenthesis = 41,
+    /// U+002A (`*`)
+    Asterisk = 42,
+    /// U+002B (`+`)
+    PlusSign = 43,
+    /// U+002C (`,`)
+    Comma = 44,
+    /// U+002D (`-`)
+    HyphenMinus = 45,
+    /// U+002E (`.`)
+    FullStop = 46,
+    /// U+002F (`/`)
+    Solidus = 47,
+    /// U+0030 (`0`)
+    Digit0 = 48,
+    /// U+0031 (`1`)
+    Digit1 = 49,
+    /// U+0032 (`2`)
+    Digit2 = 50,
+    /// U+0033 (`3`)
+    Digit3 = 51,
+    /// U+0034 (`4`)
+    Digit4 = 52,
+    /// U+0035 (`5`)
+    Digit5 = 53,
+    /// U+0036 (`6`)
+    Digit6 = 54,
+    /// U+0037 (`7`)
+    Digit7 = 55,
+    /// U+0038 (`8`)
+    Digit8 = 56,
+    /// U+0039 (`9`)
+    Digit9 = 57,
+    /// U+003A (`:`)
+    Colon = 58,
+    /// U+003B (`;`)
+    Semicolon = 59,
+    /// U+003C (`<`)
+    LessThanSign = 60,
+    /// U+003D (`=`)
+    EqualsSign = 61,
+    /// U+003E (`>`)
+    GreaterThanSign = 62,
+    /// U+003F (`?`)
+    QuestionMark = 63,
+    /// U+0040 (`@`)
+    CommercialAt = 64,
+    /// U+0041 (`A`)
+    CapitalA = 65,
+    /// U+0042 (`B`)
+    CapitalB = 66,
+    /// U+0043 (`C`)
+    CapitalC = 67,
+    /// U+0044 (`D`)
+    CapitalD = 68,
+    /// U+0045 (`E`)
+    CapitalE = 69,
+    /// U+0046 (`F`)
+    CapitalF = 70,
+    /// U+0047 (`G`)
+    CapitalG = 71,
+    /// U+0048 (`H`)
+    CapitalH = 72,
+    /// U+0049 (`I`)
+    CapitalI = 73,
+    /// U+004A (`J`)
+    CapitalJ = 74,
+    /// U+004B (`K`)
+    CapitalK = 75,
+    /// U+004C (`L`)
+    CapitalL = 76,
+    /// U+004D (`M`)
+    CapitalM = 77,
+    /// U+004E (`N`)
+    CapitalN = 78,
+    /// U+004F (`O`)
+    CapitalO = 79,
+    /// U+0050 (`P`)
+    CapitalP = 80,
+    /// U+0051 (`Q`)
+    CapitalQ = 81,
+    /// U+0052 (`R`)
+    CapitalR = 82,
+    /// U+0053 (`S`)
+    CapitalS = 83,
+    /// U+0054 (`T`)
+    CapitalT = 84,
+    /// U+0055 (`U`)
+    CapitalU = 85,
+    /// U+0056 (`V`)
+    CapitalV = 86,
+    /// U+0057 (`W`)
+    CapitalW = 87,
+    /// U+0058 (`X`)
+    CapitalX = 88,
+    /// U+0059 (`Y`)
+    CapitalY = 89,
+    /// U+005A (`Z`)
+    CapitalZ = 90,
+    /// U+005B (`[`)
+    LeftSquareBracket = 91,
+    /// U+005C (`\`)
+    ReverseSolidus = 92,
+    /// U+005D (`]`)
+    RightSquareBracket = 93,
+    /// U+005E (`^`)
+    CircumflexAccent = 94,
+    /// U+005F (`_`)
+    LowLine = 95,
+    /// U+0060 (`` ` ``)
+    GraveAccent = 96,
+    /// U+0061 (`a`)
+    SmallA = 97,
+    /// U+0062 (`b`)
+    SmallB = 98,
+    /// U+0063 (`c`)
+    SmallC = 99,
+    /// U+0064 (`d`)
+    SmallD = 100,
+    /// U+0065 (`e`)
+    SmallE = 101,
+    /// U+0066 (`f`)
+    SmallF = 102,
+    /// U+0067 (`g`)
+    SmallG = 103,
+    /// U+0068 (`h`)
+    SmallH = 104,
+    /// U+0069 (`i`)
+    SmallI = 105,
+    /// U+006A (`j`)
+    SmallJ = 106,
+    /// U+006B (`k`)
+    SmallK = 107,
+    /// U+006C (`l`)
+    SmallL = 108,
+    /// U+006D (`m`)
+    SmallM = 109,
+    /// U+006E (`n`)
+    SmallN = 110,
+    /// U+006F (`o`)
+    SmallO = 111,
+    /// U+0070 (`p`)
+    SmallP = 112,
+    /// U+0071 (`q`)
+    SmallQ = 113,
+    /// U+0072 (`r`)
+    SmallR = 114,
+    /// U+0073 (`s`)
+    SmallS = 115,
+    /// U+0074 (`t`)
+    SmallT = 116,
+    /// U+0075 (`u`)
+    SmallU = 117,
+    /// U+0076 (`v`)
+    SmallV = 118,
+    /// U+0077 (`w`)
+    SmallW = 119,
+    /// U+0078 (`x`)
+    SmallX = 120,
+    /// U+0079 (`y`)
+    SmallY = 121,
+    /// U+007A (`z`)
+    SmallZ = 122,
+    /// U+007B (`{`)
+    LeftCurlyBracket = 123,
+    /// U+007C (`|`)
+    VerticalLine = 124,
+    /// U+007D (`}`)
+    RightCurlyBracket = 125,
+    /// U+007E (`~`)
+    Tilde = 126,
+    /// U+007F
+    Delete = 127,
+}
+
+impl AsciiChar {
+    /// Creates an ASCII character from the byte `b`,
+    /// or returns `None` if it's too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii;
+    ///
+    /// assert_eq!(ascii::Char::from_u8(b'a'), Some(ascii::Char::SmallA));
+    /// assert_eq!(ascii::Char::from_u8(0x80), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn from_u8(b: u8) -> Option<Self> {
+        if b <= 127 {
+            // SAFETY: Just checked that `b` is in-range
+            Some(unsafe { Self::from_u8_unchecked(b) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates an ASCII character from the byte `b`,
+    /// without checking whether it's valid.
+    ///
+    /// # Safety
+    ///
+    /// `b` must be in `0..=127`, or else this is UB.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const unsafe fn from_u8_unchecked(b: u8) -> Self {
+        // SAFETY: Our safety precondition is that `b` is in-range.
+        unsafe { transmute(b) }
+    }
+
+    /// When passed the *number* `0`, `1`, …, `9`, returns the *character*
+    /// `'0'`, `'1'`, …, `'9'` respectively.
+    ///
+    /// If `d >= 10`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii;
+    ///
+    /// assert_eq!(ascii::Char::digit(7), Some(ascii::Char::Digit7));
+    /// assert_eq!(ascii::Char::digit(10), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn digit(d: u8) -> Option<Self> {
+        if d < 10 {
+            // SAFETY: Just checked it's in-range.
+            Some(unsafe { Self::digit_unchecked(d) })
+        } else {
+            None
+        }
+    }
+
+    /// When passed the *number* `0`, `1`, …, `9`, returns the *character*
+    /// `'0'`, `'1'`, …, `'9'` respectively, without checking that it's in-range.
+    ///
+    /// # Safety
+    ///
+    /// This is immediate UB if called with `d > 64`.
+    ///
+    /// If `d >= 10` and `d <= 64`, this is allowed to return any value or panic.
+    /// Notably, it should not be expected to return hex digits, or any other
+    /// reasonable extension of the decimal digits.
+    ///
+    /// (This loose safety condition is intended to simplify soundness proofs
+    /// when writing code using this method, since the implementation doesn't
+    /// need something really specific, not to make those other arguments do
+    /// something useful. It might be tightened before stabilization.)
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const unsafe fn digit_unchecked(d: u8) -> Self {
+        debug_assert!(d < 10);
+
+        // SAFETY: `'0'` through `'9'` are U+00030 through U+0039,
+        // so because `d` must be 64 or less the addition can return at most
+        // 112 (0x70), which doesn't overflow and is within the ASCII range.
+        unsafe { Self::from_u8_unchecked(b'0' + d) }
+    }
+
+    /// Gets this ASCII character as a byte.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Gets this ASCII character as a `char` Unicode Scalar Value.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn to_char(self) -> char {
+        self as u8 as char
+    }
+
+    /// Views this ASCII character as a one-code-unit UTF-8 `str`.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        crate::slice::from_ref(self).as_str()
+    }
+
+    /// Makes a copy of the value in its upper case equivalent.
+    ///
+    /// Letters 'a' to 'z' are mapped to 'A' to 'Z'; all other characters
+    /// are unchanged. See [`u8::to_ascii_uppercase`].
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[must_use = "to uppercase the value in-place, use `make_uppercase()`"]
+    #[inline]
+    pub const fn to_uppercase(self) -> Self {
+        // SAFETY: Changing the case of an ASCII byte keeps it ASCII.
+        unsafe { Self::from_u8_unchecked(self.to_u8().to_ascii_uppercase()) }
+    }
+
+    /// Makes a copy of the value in its lower case equivalent.
+    ///
+    /// Letters 'A' to 'Z' are mapped to 'a' to 'z'; all other characters
+    /// are unchanged. See [`u8::to_ascii_lowercase`].
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[must_use = "to lowercase the value in-place, use `make_lowercase()`"]
+    #[inline]
+    pub const fn to_lowercase(self) -> Self {
+        // SAFETY: Changing the case of an ASCII byte keeps it ASCII.
+        unsafe { Self::from_u8_unchecked(self.to_u8().to_ascii_lowercase()) }
+    }
+
+    /// Converts this value to its upper case equivalent in-place.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub fn make_uppercase(&mut self) {
+        *self = self.to_uppercase();
+    }
+
+    /// Converts this value to its lower case equivalent in-place.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub fn make_lowercase(&mut self) {
+        *self = self.to_lowercase();
+    }
+
+    /// Checks that two values are a case-insensitive match.
+    ///
+    /// Equivalent to `to_lowercase(a) == to_lowercase(b)`.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn eq_ignore_case(&self, other: &Self) -> bool {
+        self.to_u8().eq_ignore_ascii_case(&other.to_u8())
+    }
+}
+
+// The classification methods mirror the `is_ascii_*` methods on `u8` and
+// `char`, without the `ascii_` infix since it's implied by the type.
+macro_rules! ascii_char_classification {
+    ($($(#[$attr:meta])* $name:ident => $u8_method:ident;)*) => {
+        impl AsciiChar {
+            $(
+                $(#[$attr])*
+                #[doc = concat!("See [`u8::", stringify!($u8_method), "`].")]
+                #[unstable(feature = "ascii_char", issue = "none")]
+                #[must_use]
+                #[inline]
+                pub const fn $name(&self) -> bool {
+                    self.to_u8().$u8_method()
+                }
+            )*
+        }
+    };
+}
+
+ascii_char_classification! {
+    /// Checks if the value is an alphabetic character: 'A'..='Z' or 'a'..='z'.
+    ///
+    is_alphabetic => is_ascii_alphabetic;
+    /// Checks if the value is an uppercase character: 'A'..='Z'.
+    ///
+    is_uppercase => is_ascii_uppercase;
+    /// Checks if the value is a lowercase character: 'a'..='z'.
+    ///
+    is_lowercase => is_ascii_lowercase;
+    /// Checks if the value is an alphanumeric character: 'A'..='Z', 'a'..='z'
+    /// or '0'..='9'.
+    ///
+    is_alphanumeric => is_ascii_alphanumeric;
+    /// Checks if the value is a decimal digit: '0'..='9'.
+    ///
+    is_digit => is_ascii_digit;
+    /// Checks if the value is a hexadecimal digit: '0'..='9', 'A'..='F' or
+    /// 'a'..='f'.
+    ///
+    is_hexdigit => is_ascii_hexdigit;
+    /// Checks if the value is a punctuation character.
+    ///
+    is_punctuation => is_ascii_punctuation;
+    /// Checks if the value is a graphic character: '!'..='~'.
+    ///
+    is_graphic => is_ascii_graphic;
+    /// Checks if the value is a whitespace character as defined by the
+    /// WhatWG Infra Standard.
+    ///
+    is_whitespace => is_ascii_whitespace;
+    /// Checks if the value is a control character.
+    ///
+    is_control => is_ascii_control;
+}
+
+impl [AsciiChar] {
+    /// Views this slice of ASCII characters as a UTF-8 `str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii::Char;
+    ///
+    /// let hello = [Char::CapitalH, Char::SmallI, Char::ExclamationMark];
+    /// assert_eq!(hello.as_str(), "Hi!");
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        let ascii_ptr: *const Self = self;
+        let str_ptr = ascii_ptr as *const str;
+        // SAFETY: Each ASCII codepoint in UTF-8 is encoded as one single-byte
+        // code unit having the same value as the ASCII byte.
+        unsafe { &*str_ptr }
+    }
+
+    /// Views this slice of ASCII characters as a slice of `u8` bytes.
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "none")]
+impl From<AsciiChar> for u8 {
+    #[inline]
+    fn from(chr: AsciiChar) -> u8 {
+        chr.to_u8()
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "none")]
+impl From<AsciiChar> for char {
+    #[inline]
+    fn from(chr: AsciiChar) -> char {
+        chr.to_char()
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "none")]
+impl fmt::Display for AsciiChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <str as fmt::Display>::fmt(self.as_str(), f)
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "none")]
+impl fmt::Debug for AsciiChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <char as fmt::Debug>::fmt(&self.to_char(), f)
+    }
+}
@@ -1,5 +1,8 @@
 use crate::num::NonZeroUsize;
-use crate::{iter::FusedIterator, ops::Try};
+use crate::{
+    iter::{FusedIterator, TrustedLen},
+    ops::Try,
+};
 
 /// An iterator that repeats endlessly.
 ///
@@ -106,3 +109,9 @@ fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
 
 #[stable(feature = "fused", since = "1.26.0")]
 impl<I> FusedIterator for Cycle<I> where I: Clone + Iterator {}
+
+// The length of a `Cycle` is either zero or infinite, and since `I: TrustedLen`
+// the hint for `orig` can't be `(0, None)`, so `size_hint` reports exactly
+// `(0, Some(0))` or `(usize::MAX, None)`.
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<I> TrustedLen for Cycle<I> where I: Clone + TrustedLen {}
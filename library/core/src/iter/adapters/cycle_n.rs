@@ -0,0 +1,121 @@
+use crate::iter::{FusedIterator, TrustedLen};
+use crate::ops::Try;
+
+/// An iterator that repeats the underlying iterator a fixed number of times.
+///
+/// This `struct` is created by the [`cycle_n`] method on [`Iterator`]. See its
+/// documentation for more.
+///
+/// [`cycle_n`]: Iterator::cycle_n
+/// [`Iterator`]: trait.Iterator.html
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_cycle_n", reason = "recently added", issue = "none")]
+pub struct CycleN<I> {
+    orig: I,
+    iter: I,
+    // The number of passes left, including the one `iter` is currently on.
+    count: usize,
+}
+
+impl<I: Clone> CycleN<I> {
+    pub(in crate::iter) fn new(iter: I, count: usize) -> CycleN<I> {
+        CycleN { orig: iter.clone(), iter, count }
+    }
+}
+
+#[unstable(feature = "iter_cycle_n", reason = "recently added", issue = "none")]
+impl<I> Iterator for CycleN<I>
+where
+    I: Clone + Iterator,
+{
+    type Item = <I as Iterator>::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<<I as Iterator>::Item> {
+        if self.count == 0 {
+            return None;
+        }
+        match self.iter.next() {
+            None => {
+                self.count -= 1;
+                if self.count == 0 {
+                    return None;
+                }
+                self.iter = self.orig.clone();
+                let next = self.iter.next();
+                // a fresh pass that is immediately empty means every pass is
+                // empty, so don't bother cloning the remaining ones
+                if next.is_none() {
+                    self.count = 0;
+                }
+                next
+            }
+            y => y,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.count == 0 {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+        let (orig_lower, orig_upper) = self.orig.size_hint();
+        let passes = self.count - 1;
+
+        let lower = orig_lower.saturating_mul(passes).saturating_add(lower);
+        let upper = match (upper, orig_upper) {
+            (Some(upper), Some(orig_upper)) => {
+                orig_upper.checked_mul(passes).and_then(|n| n.checked_add(upper))
+            }
+            _ => None,
+        };
+
+        (lower, upper)
+    }
+
+    #[inline]
+    fn try_fold<Acc, F, R>(&mut self, mut acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        if self.count == 0 {
+            return try { acc };
+        }
+
+        // finish the pass in progress, then fold over whole passes of fresh
+        // clones until we run out of them or one of them turns out empty
+        acc = self.iter.try_fold(acc, &mut f)?;
+        loop {
+            self.count -= 1;
+            if self.count == 0 {
+                return try { acc };
+            }
+            self.iter = self.orig.clone();
+
+            let mut is_empty = true;
+            acc = self.iter.try_fold(acc, |acc, x| {
+                is_empty = false;
+                f(acc, x)
+            })?;
+
+            if is_empty {
+                self.count = 0;
+                return try { acc };
+            }
+        }
+    }
+
+    impl_fold_via_try_fold! { fold -> try_fold }
+}
+
+#[unstable(feature = "iter_cycle_n", reason = "recently added", issue = "none")]
+impl<I> FusedIterator for CycleN<I> where I: Clone + Iterator {}
+
+// The hints of `iter` and `orig` are exact, so the sum of `count - 1` copies
+// of the latter and one of the former is too, or overflows to `None`.
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<I> TrustedLen for CycleN<I> where I: Clone + TrustedLen {}
@@ -513,6 +513,13 @@ fn size_hint(&self) -> (usize, Option<usize>) {
             return (lower, upper);
         }
 
+        if let Some(max_size) = <<I as Iterator>::Item as ConstSizeIntoIterator>::max_size() {
+            let upper = self.iter.size_hint().1;
+            let upper = try { fhi?.checked_add(bhi?)?.checked_add(max_size.checked_mul(upper?)?)? };
+
+            return (lo, upper);
+        }
+
         match (self.iter.size_hint(), fhi, bhi) {
             ((0, Some(0)), Some(a), Some(b)) => (lo, a.checked_add(b)),
             _ => (lo, None),
@@ -663,6 +670,10 @@ fn advance<U: DoubleEndedIterator>(n: usize, iter: &mut U) -> ControlFlow<(), us
 trait ConstSizeIntoIterator: IntoIterator {
     // FIXME(#31844): convert to an associated const once specialization supports that
     fn size() -> Option<usize>;
+
+    /// An upper bound on the number of items, for types whose exact size isn't
+    /// known statically but can never exceed some constant.
+    fn max_size() -> Option<usize>;
 }
 
 impl<T> ConstSizeIntoIterator for T
@@ -673,6 +684,11 @@ impl<T> ConstSizeIntoIterator for T
     default fn size() -> Option<usize> {
         None
     }
+
+    #[inline]
+    default fn max_size() -> Option<usize> {
+        None
+    }
 }
 
 impl<T, const N: usize> ConstSizeIntoIterator for [T; N] {
@@ -696,6 +712,48 @@ fn size() -> Option<usize> {
     }
 }
 
+impl<T> ConstSizeIntoIterator for Option<T> {
+    #[inline]
+    fn max_size() -> Option<usize> {
+        Some(1)
+    }
+}
+
+impl<T> ConstSizeIntoIterator for &Option<T> {
+    #[inline]
+    fn max_size() -> Option<usize> {
+        Some(1)
+    }
+}
+
+impl<T> ConstSizeIntoIterator for &mut Option<T> {
+    #[inline]
+    fn max_size() -> Option<usize> {
+        Some(1)
+    }
+}
+
+impl<T, E> ConstSizeIntoIterator for Result<T, E> {
+    #[inline]
+    fn max_size() -> Option<usize> {
+        Some(1)
+    }
+}
+
+impl<T, E> ConstSizeIntoIterator for &Result<T, E> {
+    #[inline]
+    fn max_size() -> Option<usize> {
+        Some(1)
+    }
+}
+
+impl<T, E> ConstSizeIntoIterator for &mut Result<T, E> {
+    #[inline]
+    fn max_size() -> Option<usize> {
+        Some(1)
+    }
+}
+
 #[doc(hidden)]
 #[unstable(feature = "std_internals", issue = "none")]
 // FIXME(#20400): Instead of this helper trait there should be multiple impl TrustedLen for Flatten<>
@@ -1,4 +1,5 @@
 use super::Peekable;
+use crate::iter::FusedIterator;
 
 /// An iterator adapter that places a separator between all elements.
 ///
@@ -57,6 +58,14 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     }
 }
 
+#[unstable(feature = "iter_intersperse", reason = "recently added", issue = "79524")]
+impl<I> FusedIterator for Intersperse<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
+
 /// An iterator adapter that places a separator between all elements.
 ///
 /// This `struct` is created by [`Iterator::intersperse_with`]. See its
@@ -145,6 +154,14 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     }
 }
 
+#[unstable(feature = "iter_intersperse", reason = "recently added", issue = "79524")]
+impl<I, G> FusedIterator for IntersperseWith<I, G>
+where
+    I: FusedIterator,
+    G: FnMut() -> I::Item,
+{
+}
+
 fn intersperse_size_hint<I>(iter: &I, needs_sep: bool) -> (usize, Option<usize>)
 where
     I: Iterator,
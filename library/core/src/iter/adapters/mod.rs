@@ -7,6 +7,7 @@
 mod cloned;
 mod copied;
 mod cycle;
+mod cycle_n;
 mod enumerate;
 mod filter;
 mod filter_map;
@@ -25,6 +26,7 @@
 mod take;
 mod take_while;
 mod zip;
+mod zip_all;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::{
@@ -39,6 +41,9 @@
 #[unstable(feature = "std_internals", issue = "none")]
 pub use self::by_ref_sized::ByRefSized;
 
+#[unstable(feature = "iter_cycle_n", reason = "recently added", issue = "none")]
+pub use self::cycle_n::CycleN;
+
 #[stable(feature = "iter_cloned", since = "1.1.0")]
 pub use self::cloned::Cloned;
 
@@ -66,6 +71,9 @@
 #[stable(feature = "iter_zip", since = "1.59.0")]
 pub use self::zip::zip;
 
+#[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+pub use self::zip_all::{OneOrBoth, ZipAll};
+
 /// This trait provides transitive access to source-stage in an iterator-adapter pipeline
 /// under the conditions that
 /// * the iterator source `S` itself implements `SourceIter<Source = S>`
@@ -145,7 +153,10 @@ pub unsafe trait SourceIter {
 ///
 /// If a `ControlFlow::Break` is encountered, the iterator stops and the
 /// residual is stored.
-pub(crate) struct GenericShunt<'a, I, R> {
+#[unstable(feature = "std_internals", issue = "none")]
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct GenericShunt<'a, I, R> {
     iter: I,
     residual: &'a mut Option<R>,
 }
@@ -166,7 +166,15 @@ fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<I: ExactSizeIterator> ExactSizeIterator for Peekable<I> {}
+impl<I: ExactSizeIterator> ExactSizeIterator for Peekable<I> {
+    fn is_empty(&self) -> bool {
+        match self.peeked {
+            Some(None) => true,
+            Some(Some(_)) => false,
+            None => self.iter.is_empty(),
+        }
+    }
+}
 
 #[stable(feature = "fused", since = "1.26.0")]
 impl<I: FusedIterator> FusedIterator for Peekable<I> {}
@@ -1,4 +1,8 @@
-use crate::{intrinsics, iter::from_fn, ops::Try};
+use crate::{
+    intrinsics,
+    iter::{from_fn, FusedIterator},
+    ops::Try,
+};
 
 /// An iterator for stepping iterators by a custom amount.
 ///
@@ -233,3 +237,6 @@ fn nth_back<I: DoubleEndedIterator>(
 // StepBy can only make the iterator shorter, so the len will still fit.
 #[stable(feature = "iterator_step_by", since = "1.28.0")]
 impl<I> ExactSizeIterator for StepBy<I> where I: ExactSizeIterator {}
+
+#[stable(feature = "step_by_fused", since = "CURRENT_RUSTC_VERSION")]
+impl<I> FusedIterator for StepBy<I> where I: FusedIterator {}
@@ -129,6 +129,10 @@ fn next_back(&mut self) -> Option<Self::Item>
     where
         A: DoubleEndedIterator + ExactSizeIterator,
         B: DoubleEndedIterator + ExactSizeIterator;
+    fn is_empty(&self) -> bool
+    where
+        A: ExactSizeIterator,
+        B: ExactSizeIterator;
     // This has the same safety requirements as `Iterator::__iterator_get_unchecked`
     unsafe fn get_unchecked(&mut self, idx: usize) -> <Self as Iterator>::Item
     where
@@ -222,6 +226,15 @@ impl<A, B> ZipImpl<A, B> for Zip<A, B>
         (lower, upper)
     }
 
+    #[inline]
+    default fn is_empty(&self) -> bool
+    where
+        A: ExactSizeIterator,
+        B: ExactSizeIterator,
+    {
+        self.a.is_empty() || self.b.is_empty()
+    }
+
     default unsafe fn get_unchecked(&mut self, _idx: usize) -> <Self as Iterator>::Item
     where
         Self: TrustedRandomAccessNoCoerce,
@@ -244,6 +257,15 @@ impl<A, B> ZipImpl<A, B> for Zip<A, B>
         (size, Some(size))
     }
 
+    #[inline]
+    default fn is_empty(&self) -> bool
+    where
+        A: ExactSizeIterator,
+        B: ExactSizeIterator,
+    {
+        self.a.size() == 0 || self.b.size() == 0
+    }
+
     #[inline]
     unsafe fn get_unchecked(&mut self, idx: usize) -> <Self as Iterator>::Item {
         let idx = self.index + idx;
@@ -298,6 +320,15 @@ fn size_hint(&self) -> (usize, Option<usize>) {
         (len, Some(len))
     }
 
+    #[inline]
+    fn is_empty(&self) -> bool
+    where
+        A: ExactSizeIterator,
+        B: ExactSizeIterator,
+    {
+        self.index == self.len
+    }
+
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         let delta = cmp::min(n, self.len - self.index);
@@ -380,6 +411,10 @@ impl<A, B> ExactSizeIterator for Zip<A, B>
     A: ExactSizeIterator,
     B: ExactSizeIterator,
 {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        ZipImpl::is_empty(self)
+    }
 }
 
 #[doc(hidden)]
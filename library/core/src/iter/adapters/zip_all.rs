@@ -0,0 +1,160 @@
+use crate::cmp::{self, Ordering};
+use crate::iter::{Fuse, FusedIterator, TrustedLen};
+
+/// An iterator that iterates two other iterators simultaneously, continuing
+/// until both of them are exhausted.
+///
+/// This `struct` is created by [`Iterator::zip_all`]. See its
+/// documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+pub struct ZipAll<A, B> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+}
+
+impl<A: Iterator, B: Iterator> ZipAll<A, B> {
+    pub(in crate::iter) fn new(a: A, b: B) -> ZipAll<A, B> {
+        ZipAll { a: a.fuse(), b: b.fuse() }
+    }
+}
+
+/// A value yielded by [`ZipAll`]: either an item from both iterators, or
+/// an item from the one that is still going after the other ran out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+pub enum OneOrBoth<A, B> {
+    /// Both iterators yielded an item.
+    Both(A, B),
+    /// Only the first iterator yielded an item.
+    Left(A),
+    /// Only the second iterator yielded an item.
+    Right(B),
+}
+
+impl<A, B> OneOrBoth<A, B> {
+    /// Returns the item from the first iterator, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_zip_all)]
+    ///
+    /// use std::iter::OneOrBoth;
+    ///
+    /// assert_eq!(OneOrBoth::<_, ()>::Left(1).left(), Some(1));
+    /// assert_eq!(OneOrBoth::Both(1, 'a').left(), Some(1));
+    /// assert_eq!(OneOrBoth::<i32, _>::Right('a').left(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+    pub fn left(self) -> Option<A> {
+        match self {
+            OneOrBoth::Both(a, _) | OneOrBoth::Left(a) => Some(a),
+            OneOrBoth::Right(_) => None,
+        }
+    }
+
+    /// Returns the item from the second iterator, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_zip_all)]
+    ///
+    /// use std::iter::OneOrBoth;
+    ///
+    /// assert_eq!(OneOrBoth::<(), _>::Right('a').right(), Some('a'));
+    /// assert_eq!(OneOrBoth::Both(1, 'a').right(), Some('a'));
+    /// assert_eq!(OneOrBoth::<_, char>::Left(1).right(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+    pub fn right(self) -> Option<B> {
+        match self {
+            OneOrBoth::Both(_, b) | OneOrBoth::Right(b) => Some(b),
+            OneOrBoth::Left(_) => None,
+        }
+    }
+}
+
+#[inline]
+fn one_or_both<A, B>(a: Option<A>, b: Option<B>) -> Option<OneOrBoth<A, B>> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(OneOrBoth::Both(a, b)),
+        (Some(a), None) => Some(OneOrBoth::Left(a)),
+        (None, Some(b)) => Some(OneOrBoth::Right(b)),
+        (None, None) => None,
+    }
+}
+
+#[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+impl<A, B> Iterator for ZipAll<A, B>
+where
+    A: Iterator,
+    B: Iterator,
+{
+    type Item = OneOrBoth<A::Item, B::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        one_or_both(self.a.next(), self.b.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+
+        let lower = cmp::max(a_lower, b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(cmp::max(x, y)),
+            _ => None,
+        };
+
+        (lower, upper)
+    }
+}
+
+#[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+impl<A, B> DoubleEndedIterator for ZipAll<A, B>
+where
+    A: DoubleEndedIterator + ExactSizeIterator,
+    B: DoubleEndedIterator + ExactSizeIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // The longer iterator's surplus comes out first when going backwards,
+        // so that both directions agree on which items end up paired.
+        match self.a.len().cmp(&self.b.len()) {
+            Ordering::Greater => self.a.next_back().map(OneOrBoth::Left),
+            Ordering::Less => self.b.next_back().map(OneOrBoth::Right),
+            Ordering::Equal => one_or_both(self.a.next_back(), self.b.next_back()),
+        }
+    }
+}
+
+#[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+impl<A, B> ExactSizeIterator for ZipAll<A, B>
+where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator,
+{
+}
+
+#[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+impl<A, B> FusedIterator for ZipAll<A, B>
+where
+    A: Iterator,
+    B: Iterator,
+{
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<A, B> TrustedLen for ZipAll<A, B>
+where
+    A: TrustedLen,
+    B: TrustedLen,
+{
+}
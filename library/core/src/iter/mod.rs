@@ -415,6 +415,8 @@ fn $fold<AAA, FFF>(mut self, init: AAA, fold: FFF) -> AAA
 pub use self::traits::TrustedLen;
 #[unstable(feature = "trusted_step", issue = "85731")]
 pub use self::traits::TrustedStep;
+#[unstable(feature = "iter_checked_sum", issue = "none")]
+pub use self::traits::{CheckedProduct, CheckedSum};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::traits::{
     DoubleEndedIterator, ExactSizeIterator, Extend, FromIterator, IntoIterator, Product, Sum,
@@ -430,8 +432,12 @@ fn $fold<AAA, FFF>(mut self, init: AAA, fold: FFF) -> AAA
 pub use self::adapters::Cloned;
 #[stable(feature = "iter_copied", since = "1.36.0")]
 pub use self::adapters::Copied;
+#[unstable(feature = "iter_cycle_n", reason = "recently added", issue = "none")]
+pub use self::adapters::CycleN;
 #[stable(feature = "iterator_flatten", since = "1.29.0")]
 pub use self::adapters::Flatten;
+#[unstable(feature = "std_internals", issue = "none")]
+pub use self::adapters::GenericShunt;
 #[stable(feature = "iter_map_while", since = "1.57.0")]
 pub use self::adapters::MapWhile;
 #[unstable(feature = "inplace_iteration", issue = "none")]
@@ -449,6 +455,8 @@ fn $fold<AAA, FFF>(mut self, init: AAA, fold: FFF) -> AAA
 };
 #[unstable(feature = "iter_intersperse", reason = "recently added", issue = "79524")]
 pub use self::adapters::{Intersperse, IntersperseWith};
+#[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+pub use self::adapters::{OneOrBoth, ZipAll};
 
 pub(crate) use self::adapters::try_process;
 pub(crate) use self::traits::UncheckedIterator;
@@ -487,7 +487,12 @@ unsafe fn backward_unchecked(start: char, count: usize) -> char {
 macro_rules! range_exact_iter_impl {
     ($($t:ty)*) => ($(
         #[stable(feature = "rust1", since = "1.0.0")]
-        impl ExactSizeIterator for ops::Range<$t> { }
+        impl ExactSizeIterator for ops::Range<$t> {
+            #[inline]
+            fn is_empty(&self) -> bool {
+                ops::Range::is_empty(self)
+            }
+        }
     )*)
 }
 
@@ -510,7 +515,12 @@ unsafe impl TrustedRandomAccessNoCoerce for ops::Range<$t> {
 macro_rules! range_incl_exact_iter_impl {
     ($($t:ty)*) => ($(
         #[stable(feature = "inclusive_range", since = "1.26.0")]
-        impl ExactSizeIterator for ops::RangeInclusive<$t> { }
+        impl ExactSizeIterator for ops::RangeInclusive<$t> {
+            #[inline]
+            fn is_empty(&self) -> bool {
+                ops::RangeInclusive::is_empty(self)
+            }
+        }
     )*)
 }
 
@@ -721,6 +731,15 @@ fn size_hint(&self) -> (usize, Option<usize>) {
         }
     }
 
+    #[inline]
+    fn count(self) -> usize {
+        if self.start < self.end {
+            Step::steps_between(&self.start, &self.end).expect("count overflowed usize")
+        } else {
+            0
+        }
+    }
+
     #[inline]
     fn nth(&mut self, n: usize) -> Option<A> {
         self.spec_nth(n)
@@ -772,6 +791,10 @@ unsafe fn __iterator_get_unchecked(&mut self, idx: usize) -> Self::Item
 //   For integer types in `RangeInclusive<_>`
 //   this is the case for types *strictly narrower* than `usize`
 //   since e.g. `(0..=u64::MAX).len()` would be `u64::MAX + 1`.
+// * `Range<u64>` and `Range<i64>` fit on 64-bit targets, but a trait impl that
+//   only exists on some targets would make code silently non-portable. Those
+//   ranges are `TrustedLen` instead, which is what `collect` and `extend` use
+//   to reserve exactly.
 range_exact_iter_impl! {
     usize u8 u16
     isize i8 i16
@@ -1112,6 +1135,17 @@ fn size_hint(&self) -> (usize, Option<usize>) {
         }
     }
 
+    #[inline]
+    fn count(self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        Step::steps_between(&self.start, &self.end)
+            .and_then(|steps| steps.checked_add(1))
+            .expect("count overflowed usize")
+    }
+
     #[inline]
     fn nth(&mut self, n: usize) -> Option<A> {
         if self.is_empty() {
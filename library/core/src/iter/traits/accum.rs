@@ -1,5 +1,8 @@
 use crate::iter;
-use crate::num::Wrapping;
+use crate::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
+use crate::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use crate::num::{Saturating, Wrapping};
+use crate::ops::Add;
 
 /// Trait to represent types that can be created by summing up an iterator.
 ///
@@ -42,6 +45,48 @@ pub trait Product<A = Self>: Sized {
     fn product<I: Iterator<Item = A>>(iter: I) -> Self;
 }
 
+/// Trait to represent types that can be created by summing up an iterator,
+/// with the sum being rejected if it overflows.
+///
+/// This trait is used to implement [`Iterator::checked_sum()`]. Types which
+/// implement this trait can be generated by using the [`checked_sum()`] method
+/// on an iterator. Like [`FromIterator`], this trait should rarely be called
+/// directly.
+///
+/// [`checked_sum()`]: Iterator::checked_sum
+/// [`FromIterator`]: iter::FromIterator
+#[unstable(feature = "iter_checked_sum", issue = "none")]
+#[rustc_on_unimplemented(
+    message = "a value of type `{Self}` cannot be made by checked summing of elements of type `{A}`",
+    label = "value of type `{Self}` cannot be made by checked summing of a `std::iter::Iterator<Item={A}>`"
+)]
+pub trait CheckedSum<A = Self>: Sized {
+    /// Method which takes an iterator and generates `Self` from the elements by
+    /// "summing up" the items, returning `None` if the sum overflows.
+    fn checked_sum<I: Iterator<Item = A>>(iter: I) -> Option<Self>;
+}
+
+/// Trait to represent types that can be created by multiplying elements of an
+/// iterator, with the product being rejected if it overflows.
+///
+/// This trait is used to implement [`Iterator::checked_product()`]. Types which
+/// implement this trait can be generated by using the [`checked_product()`]
+/// method on an iterator. Like [`FromIterator`], this trait should rarely be
+/// called directly.
+///
+/// [`checked_product()`]: Iterator::checked_product
+/// [`FromIterator`]: iter::FromIterator
+#[unstable(feature = "iter_checked_sum", issue = "none")]
+#[rustc_on_unimplemented(
+    message = "a value of type `{Self}` cannot be made by checked multiplication of elements of type `{A}`",
+    label = "value of type `{Self}` cannot be made by checked multiplication of a `std::iter::Iterator<Item={A}>`"
+)]
+pub trait CheckedProduct<A = Self>: Sized {
+    /// Method which takes an iterator and generates `Self` from the elements by
+    /// multiplying the items, returning `None` if the product overflows.
+    fn checked_product<I: Iterator<Item = A>>(iter: I) -> Option<Self>;
+}
+
 macro_rules! integer_sum_product {
     (@impls $zero:expr, $one:expr, #[$attr:meta], $($a:ty)*) => ($(
         #[$attr]
@@ -95,19 +140,96 @@ fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
         integer_sum_product!(@impls Wrapping(0), Wrapping(1),
                 #[stable(feature = "wrapping_iter_arith", since = "1.14.0")],
                 $(Wrapping<$a>)*);
+        integer_sum_product!(@impls Saturating(0), Saturating(1),
+                #[unstable(feature = "saturating_int_impl", issue = "87920")],
+                $(Saturating<$a>)*);
     );
 }
 
+macro_rules! integer_checked_sum_product {
+    ($($a:ty)*) => ($(
+        #[unstable(feature = "iter_checked_sum", issue = "none")]
+        impl CheckedSum for $a {
+            fn checked_sum<I: Iterator<Item=Self>>(mut iter: I) -> Option<Self> {
+                iter.try_fold(0, |a: Self, b| a.checked_add(b))
+            }
+        }
+
+        #[unstable(feature = "iter_checked_sum", issue = "none")]
+        impl CheckedProduct for $a {
+            fn checked_product<I: Iterator<Item=Self>>(mut iter: I) -> Option<Self> {
+                iter.try_fold(1, |a: Self, b| a.checked_mul(b))
+            }
+        }
+
+        #[unstable(feature = "iter_checked_sum", issue = "none")]
+        impl<'a> CheckedSum<&'a $a> for $a {
+            fn checked_sum<I: Iterator<Item=&'a Self>>(mut iter: I) -> Option<Self> {
+                iter.try_fold(0, |a: Self, b| a.checked_add(*b))
+            }
+        }
+
+        #[unstable(feature = "iter_checked_sum", issue = "none")]
+        impl<'a> CheckedProduct<&'a $a> for $a {
+            fn checked_product<I: Iterator<Item=&'a Self>>(mut iter: I) -> Option<Self> {
+                iter.try_fold(1, |a: Self, b| a.checked_mul(*b))
+            }
+        }
+    )*);
+}
+
+// A wrapped product of nonzero values may be zero, so unlike the plain
+// integer impls these panic on overflow even without overflow checks.
+macro_rules! nonzero_product {
+    ($($a:ty)*) => ($(
+        /// # Panics
+        ///
+        /// Panics if the product overflows, regardless of whether overflow
+        /// checks are enabled.
+        #[stable(feature = "nonzero_iter_arith", since = "CURRENT_RUSTC_VERSION")]
+        impl Product for $a {
+            fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+                iter.fold(
+                    <$a>::new(1).unwrap(),
+                    |a, b| a.checked_mul(b).expect("attempt to multiply with overflow"),
+                )
+            }
+        }
+
+        /// # Panics
+        ///
+        /// Panics if the product overflows, regardless of whether overflow
+        /// checks are enabled.
+        #[stable(feature = "nonzero_iter_arith", since = "CURRENT_RUSTC_VERSION")]
+        impl<'a> Product<&'a $a> for $a {
+            fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+    )*);
+}
+
 macro_rules! float_sum_product {
     ($($a:ident)*) => ($(
+        impl FloatSum for $a {
+            const ZERO: Self = 0.0;
+        }
+
+        impl<'a> SpecFloatSum<$a> for crate::slice::Iter<'a, $a> {
+            fn spec_float_sum(self) -> $a {
+                pairwise_sum_slice(self.as_slice())
+            }
+        }
+
+        /// The sum is computed with blocked pairwise summation rather than by
+        /// adding the elements one after the other, which keeps the rounding
+        /// error growing with the logarithm of the number of elements instead
+        /// of linearly. The exact order in which the elements are added is
+        /// unspecified.
         #[stable(feature = "iter_arith_traits", since = "1.12.0")]
         impl Sum for $a {
             fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
-                iter.fold(
-                    0.0,
-                    #[rustc_inherit_overflow_checks]
-                    |a, b| a + b,
-                )
+                pairwise_sum_iter(iter)
             }
         }
 
@@ -122,14 +244,12 @@ fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
             }
         }
 
+        /// Like summing up owned values, this uses blocked pairwise summation.
+        /// Slice iterators are summed up directly from the underlying slice.
         #[stable(feature = "iter_arith_traits", since = "1.12.0")]
         impl<'a> Sum<&'a $a> for $a {
             fn sum<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
-                iter.fold(
-                    0.0,
-                    #[rustc_inherit_overflow_checks]
-                    |a, b| a + b,
-                )
+                SpecFloatSum::spec_float_sum(iter)
             }
         }
 
@@ -146,8 +266,111 @@ fn product<I: Iterator<Item=&'a Self>>(iter: I) -> Self {
     )*)
 }
 
+/// Floating point types that can be summed with [`pairwise_sum_iter`] and
+/// [`pairwise_sum_slice`].
+trait FloatSum: Copy + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+/// Number of independent accumulators used to sum up a block. Splitting the
+/// additions across them breaks the dependency chain, so the block loop
+/// doesn't have to wait for the latency of every single addition.
+const PAIRWISE_LANES: usize = 8;
+
+/// Number of elements that are summed up as one block before the partial sums
+/// are combined pairwise.
+const PAIRWISE_BLOCK: usize = 256;
+
+#[inline]
+fn reduce_lanes<F: FloatSum>(l: [F; PAIRWISE_LANES]) -> F {
+    ((l[0] + l[1]) + (l[2] + l[3])) + ((l[4] + l[5]) + (l[6] + l[7]))
+}
+
+/// Sums up a slice by recursively splitting it in halves, down to blocks of
+/// at most `PAIRWISE_BLOCK` elements.
+fn pairwise_sum_slice<F: FloatSum>(v: &[F]) -> F {
+    if v.len() > PAIRWISE_BLOCK {
+        // Split on a block boundary so that only the last block is partial.
+        let mid = (v.len() / PAIRWISE_BLOCK + 1) / 2 * PAIRWISE_BLOCK;
+        let (left, right) = v.split_at(mid);
+        return pairwise_sum_slice(left) + pairwise_sum_slice(right);
+    }
+
+    let mut lanes = [F::ZERO; PAIRWISE_LANES];
+    let mut chunks = v.chunks_exact(PAIRWISE_LANES);
+    for chunk in &mut chunks {
+        for i in 0..PAIRWISE_LANES {
+            lanes[i] = lanes[i] + chunk[i];
+        }
+    }
+    let mut sum = reduce_lanes(lanes);
+    for &x in chunks.remainder() {
+        sum = sum + x;
+    }
+    sum
+}
+
+/// Sums up an iterator in the same way as [`pairwise_sum_slice`], without
+/// knowing its length in advance.
+///
+/// Block sums are combined like the carries of a binary counter: `partials[k]`
+/// holds the sum of `2^k` blocks whenever bit `k` of `blocks` is set, so only
+/// sums covering the same number of elements are ever added to each other.
+fn pairwise_sum_iter<F: FloatSum, I: Iterator<Item = F>>(mut iter: I) -> F {
+    let mut partials = [F::ZERO; usize::BITS as usize];
+    let mut blocks: usize = 0;
+    loop {
+        let mut lanes = [F::ZERO; PAIRWISE_LANES];
+        let len = iter.by_ref().take(PAIRWISE_BLOCK).fold(0, |i, x| {
+            lanes[i % PAIRWISE_LANES] = lanes[i % PAIRWISE_LANES] + x;
+            i + 1
+        });
+        if len == 0 {
+            break;
+        }
+
+        let mut sum = reduce_lanes(lanes);
+        let mut level = 0;
+        while blocks & (1 << level) != 0 {
+            sum = partials[level] + sum;
+            level += 1;
+        }
+        partials[level] = sum;
+        blocks += 1;
+
+        if len < PAIRWISE_BLOCK {
+            break;
+        }
+    }
+
+    let mut total = F::ZERO;
+    for (level, &partial) in partials.iter().enumerate() {
+        if blocks & (1 << level) != 0 {
+            total = partial + total;
+        }
+    }
+    total
+}
+
+/// Specialization trait used for `Sum<&'a f32>` and `Sum<&'a f64>`, so that
+/// slice iterators are summed up directly from the underlying slice.
+trait SpecFloatSum<F> {
+    fn spec_float_sum(self) -> F;
+}
+
+impl<'a, F: FloatSum + 'a, I: Iterator<Item = &'a F>> SpecFloatSum<F> for I {
+    default fn spec_float_sum(self) -> F {
+        pairwise_sum_iter(self.copied())
+    }
+}
+
 integer_sum_product! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+integer_checked_sum_product! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
 float_sum_product! { f32 f64 }
+nonzero_product! {
+    NonZeroI8 NonZeroI16 NonZeroI32 NonZeroI64 NonZeroI128 NonZeroIsize
+    NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128 NonZeroUsize
+}
 
 #[stable(feature = "iter_arith_traits_result", since = "1.16.0")]
 impl<T, U, E> Sum<Result<U, E>> for Result<T, E>
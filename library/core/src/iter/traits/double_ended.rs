@@ -366,6 +366,85 @@ fn check<T>(mut predicate: impl FnMut(&T) -> bool) -> impl FnMut((), T) -> Contr
 
         self.try_rfold((), check(predicate)).break_value()
     }
+
+    /// Applies a fallible function to each element of the iterator, starting
+    /// from the back, stopping at the first error and returning that error.
+    ///
+    /// This is the reverse version of [`Iterator::try_for_each()`]: it
+    /// takes elements starting from the back of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_try_rfor_each)]
+    ///
+    /// let a = [1, 2, 3, 4];
+    /// let mut seen = Vec::new();
+    ///
+    /// let mut iter = a.iter();
+    /// let res = iter.try_rfor_each(|&x| {
+    ///     if x == 2 {
+    ///         return Err(x);
+    ///     }
+    ///     seen.push(x);
+    ///     Ok(())
+    /// });
+    /// assert_eq!(res, Err(2));
+    /// assert_eq!(seen, [4, 3]);
+    ///
+    /// // It short-circuited, so the remaining items are still in the iterator:
+    /// assert_eq!(iter.next_back(), Some(&1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_try_rfor_each", issue = "none")]
+    fn try_rfor_each<F, R>(&mut self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>,
+    {
+        #[inline]
+        fn call<T, R>(mut f: impl FnMut(T) -> R) -> impl FnMut((), T) -> R {
+            move |(), x| f(x)
+        }
+
+        self.try_rfold((), call(f))
+    }
+
+    /// Applies function to the elements of iterator, starting from the back,
+    /// and returns the first non-none result.
+    ///
+    /// `iter.rfind_map(f)` is equivalent to `iter.rev().find_map(f)`, but
+    /// leaves the remaining elements in `iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_rfind_map)]
+    ///
+    /// let a = ["1", "NaN", "2", "lol"];
+    ///
+    /// let last_number = a.iter().rfind_map(|s| s.parse::<i32>().ok());
+    ///
+    /// assert_eq!(last_number, Some(2));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_rfind_map", issue = "none")]
+    fn rfind_map<B, F>(&mut self, f: F) -> Option<B>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        #[inline]
+        fn check<T, B>(mut f: impl FnMut(T) -> Option<B>) -> impl FnMut((), T) -> ControlFlow<B> {
+            move |(), x| match f(x) {
+                Some(x) => ControlFlow::Break(x),
+                None => ControlFlow::Continue(()),
+            }
+        }
+
+        self.try_rfold((), check(f)).break_value()
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -6,9 +6,11 @@
 use super::super::try_process;
 use super::super::ByRefSized;
 use super::super::TrustedRandomAccessNoCoerce;
-use super::super::{ArrayChunks, Chain, Cloned, Copied, Cycle, Enumerate, Filter, FilterMap, Fuse};
+use super::super::{ArrayChunks, Chain, Cloned, Copied, Cycle, CycleN, Enumerate};
+use super::super::{CheckedProduct, CheckedSum};
+use super::super::{Filter, FilterMap, Fuse};
 use super::super::{FlatMap, Flatten};
-use super::super::{FromIterator, Intersperse, IntersperseWith, Product, Sum, Zip};
+use super::super::{FromIterator, Intersperse, IntersperseWith, Product, Sum, Zip, ZipAll};
 use super::super::{
     Inspect, Map, MapWhile, Peekable, Rev, Scan, Skip, SkipWhile, StepBy, Take, TakeWhile,
 };
@@ -644,6 +646,78 @@ fn zip<U>(self, other: U) -> Zip<Self, U::IntoIter>
         Zip::new(self, other.into_iter())
     }
 
+    /// 'Zips up' two iterators into a single iterator of pairs, continuing
+    /// until both of them are exhausted.
+    ///
+    /// Where [`zip`] stops as soon as either iterator returns [`None`],
+    /// `zip_all()` keeps going and yields the remaining items of the longer
+    /// iterator on their own. Each item is a [`OneOrBoth`]: [`Both`] while
+    /// both iterators produce items, then [`Left`] or [`Right`] depending on
+    /// which one is left over.
+    ///
+    /// Both iterators are fused, so neither is polled again after it has
+    /// returned [`None`] once.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iter_zip_all)]
+    ///
+    /// use std::iter::OneOrBoth::{Both, Left, Right};
+    ///
+    /// let a = [1, 2, 3];
+    /// let b = ['x'];
+    ///
+    /// let mut iter = a.iter().zip_all(b.iter());
+    ///
+    /// assert_eq!(iter.next(), Some(Both(&1, &'x')));
+    /// assert_eq!(iter.next(), Some(Left(&2)));
+    /// assert_eq!(iter.next(), Some(Left(&3)));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let mut iter = b.iter().zip_all(a.iter());
+    ///
+    /// assert_eq!(iter.next(), Some(Both(&'x', &1)));
+    /// assert_eq!(iter.next(), Some(Right(&2)));
+    /// assert_eq!(iter.next(), Some(Right(&3)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// Padding the shorter side with a default value:
+    ///
+    /// ```
+    /// #![feature(iter_zip_all)]
+    ///
+    /// let a = [1, 2, 3, 4];
+    /// let b = [10, 20];
+    ///
+    /// let sums: Vec<i32> = a
+    ///     .into_iter()
+    ///     .zip_all(b)
+    ///     .map(|x| x.left().unwrap_or(0) + x.right().unwrap_or(0))
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, [11, 22, 3, 4]);
+    /// ```
+    ///
+    /// [`zip`]: Iterator::zip
+    /// [`OneOrBoth`]: crate::iter::OneOrBoth
+    /// [`Both`]: crate::iter::OneOrBoth::Both
+    /// [`Left`]: crate::iter::OneOrBoth::Left
+    /// [`Right`]: crate::iter::OneOrBoth::Right
+    #[inline]
+    #[unstable(feature = "iter_zip_all", reason = "recently added", issue = "none")]
+    #[rustc_do_not_const_check]
+    fn zip_all<U>(self, other: U) -> ZipAll<Self, U::IntoIter>
+    where
+        Self: Sized,
+        U: IntoIterator,
+    {
+        ZipAll::new(self, other.into_iter())
+    }
+
     /// Creates a new iterator which places a copy of `separator` between adjacent
     /// items of the original iterator.
     ///
@@ -2050,6 +2124,47 @@ fn collect_into<E: Extend<Self::Item>>(self, collection: &mut E) -> &mut E
         collection
     }
 
+    /// Collects all items from the iterator into an array of exactly `N` elements.
+    ///
+    /// This is a shortcut for collecting into a `Vec` and converting it with
+    /// `try_into`, without the intermediate allocation.
+    ///
+    /// If the iterator yields fewer or more than `N` items, `None` is returned
+    /// and all items that were already taken out of the iterator are dropped.
+    /// In the "more" case, only one item past the first `N` is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_collect_array)]
+    ///
+    /// let a = [1, 2, 3];
+    /// let doubled: [i32; 3] = a.iter().map(|&x| x * 2).collect_array().unwrap();
+    /// assert_eq!(doubled, [2, 4, 6]);
+    ///
+    /// assert_eq!(a.iter().collect_array::<2>(), None);
+    /// assert_eq!(a.iter().collect_array::<4>(), None);
+    /// ```
+    ///
+    /// Destructuring a known-length sequence:
+    ///
+    /// ```
+    /// #![feature(iter_collect_array)]
+    ///
+    /// let [r, g, b] = "12,34,56".split(',').collect_array().unwrap();
+    /// assert_eq!((r, g, b), ("12", "34", "56"));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_collect_array", reason = "new API", issue = "none")]
+    #[rustc_do_not_const_check]
+    fn collect_array<const N: usize>(mut self) -> Option<[Self::Item; N]>
+    where
+        Self: Sized,
+    {
+        let array = self.next_chunk().ok()?;
+        if self.next().is_some() { None } else { Some(array) }
+    }
+
     /// Consumes an iterator, creating two collections from it.
     ///
     /// The predicate passed to `partition()` can return `true`, or `false`.
@@ -3398,6 +3513,60 @@ fn cycle(self) -> Cycle<Self>
         Cycle::new(self)
     }
 
+    /// Repeats an iterator exactly `count` times.
+    ///
+    /// This is like [`cycle`], except that the resulting iterator stops after
+    /// the original iterator has been run through `count` times. Unlike
+    /// `cycle().take(n)`, it keeps the size information of the original
+    /// iterator: its [`size_hint`] covers all the remaining passes, and if
+    /// the original iterator implements [`TrustedLen`], so does the result,
+    /// which lets [`collect`] allocate exactly once.
+    ///
+    /// [`cycle`]: Iterator::cycle
+    /// [`size_hint`]: Iterator::size_hint
+    /// [`collect`]: Iterator::collect
+    /// [`TrustedLen`]: crate::iter::TrustedLen
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iter_cycle_n)]
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let mut it = a.iter().cycle_n(2);
+    ///
+    /// assert_eq!(it.size_hint(), (6, Some(6)));
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.next(), Some(&2));
+    /// assert_eq!(it.next(), Some(&3));
+    /// assert_eq!(it.next(), Some(&1));
+    /// assert_eq!(it.size_hint(), (2, Some(2)));
+    /// assert_eq!(it.next(), Some(&2));
+    /// assert_eq!(it.next(), Some(&3));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    ///
+    /// Tiling a pattern into a buffer:
+    ///
+    /// ```
+    /// #![feature(iter_cycle_n)]
+    ///
+    /// let tiles: Vec<u8> = b"ab".iter().copied().cycle_n(3).collect();
+    /// assert_eq!(tiles, b"ababab");
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_cycle_n", reason = "recently added", issue = "none")]
+    #[rustc_do_not_const_check]
+    fn cycle_n(self, count: usize) -> CycleN<Self>
+    where
+        Self: Sized + Clone,
+    {
+        CycleN::new(self, count)
+    }
+
     /// Returns an iterator over `N` elements of the iterator at a time.
     ///
     /// The chunks do not overlap. If `N` does not divide the length of the
@@ -3510,6 +3679,70 @@ fn product<P>(self) -> P
         Product::product(self)
     }
 
+    /// Sums the elements of an iterator, returning `None` if the sum overflows.
+    ///
+    /// Unlike [`sum()`], which panics on overflow when debug assertions are
+    /// enabled and wraps otherwise, this always uses checked arithmetic. It
+    /// stops consuming the iterator as soon as an overflow happens.
+    ///
+    /// An empty iterator returns `Some` of the zero value of the type.
+    ///
+    /// [`sum()`]: Iterator::sum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_checked_sum)]
+    ///
+    /// let lengths = [100u8, 50, 20];
+    /// assert_eq!(lengths.iter().checked_sum::<u8>(), Some(170));
+    ///
+    /// let lengths = [100u8, 100, 100];
+    /// assert_eq!(lengths.iter().checked_sum::<u8>(), None);
+    /// ```
+    #[unstable(feature = "iter_checked_sum", reason = "new API", issue = "none")]
+    #[rustc_do_not_const_check]
+    fn checked_sum<S>(self) -> Option<S>
+    where
+        Self: Sized,
+        S: CheckedSum<Self::Item>,
+    {
+        CheckedSum::checked_sum(self)
+    }
+
+    /// Multiplies the elements of an iterator, returning `None` if the product
+    /// overflows.
+    ///
+    /// Unlike [`product()`], which panics on overflow when debug assertions are
+    /// enabled and wraps otherwise, this always uses checked arithmetic. It
+    /// stops consuming the iterator as soon as an overflow happens.
+    ///
+    /// An empty iterator returns `Some` of the one value of the type.
+    ///
+    /// [`product()`]: Iterator::product
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_checked_sum)]
+    ///
+    /// fn factorial(n: u32) -> Option<u32> {
+    ///     (1..=n).checked_product()
+    /// }
+    /// assert_eq!(factorial(0), Some(1));
+    /// assert_eq!(factorial(12), Some(479001600));
+    /// assert_eq!(factorial(13), None);
+    /// ```
+    #[unstable(feature = "iter_checked_sum", reason = "new API", issue = "none")]
+    #[rustc_do_not_const_check]
+    fn checked_product<P>(self) -> Option<P>
+    where
+        Self: Sized,
+        P: CheckedProduct<Self::Item>,
+    {
+        CheckedProduct::checked_product(self)
+    }
+
     /// [Lexicographically](Ord#lexicographical-comparison) compares the elements of this [`Iterator`] with those
     /// of another.
     ///
@@ -16,6 +16,8 @@
     marker::{FusedIterator, TrustedLen},
 };
 
+#[unstable(feature = "iter_checked_sum", issue = "none")]
+pub use self::accum::{CheckedProduct, CheckedSum};
 #[unstable(issue = "none", feature = "inplace_iteration")]
 pub use self::marker::InPlaceIterable;
 #[unstable(feature = "trusted_step", issue = "85731")]
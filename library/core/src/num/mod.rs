@@ -45,6 +45,7 @@ macro_rules! unlikely {
 mod error;
 mod int_log10;
 mod nonzero;
+mod overflow_panic;
 #[unstable(feature = "saturating_int_impl", issue = "87920")]
 mod saturating;
 mod wrapping;
@@ -458,6 +459,25 @@ pub const fn is_ascii(&self) -> bool {
         *self & 128 == 0
     }
 
+    /// If the value of this byte is within the ASCII range, returns it as an
+    /// [ASCII character](ascii::Char). Otherwise, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii;
+    ///
+    /// assert_eq!(b'A'.as_ascii(), Some(ascii::Char::CapitalA));
+    /// assert_eq!(0xC9_u8.as_ascii(), None);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[inline]
+    pub const fn as_ascii(&self) -> Option<ascii::Char> {
+        ascii::Char::from_u8(*self)
+    }
+
     /// Makes a copy of the value in its ASCII upper case equivalent.
     ///
     /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z',
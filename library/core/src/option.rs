@@ -644,6 +644,43 @@ pub const fn is_none(&self) -> bool {
         !self.is_some()
     }
 
+    /// Returns `true` if the option is a [`Some`] value containing the given value.
+    ///
+    /// This is a shorthand for [`is_some_and`] with an equality check:
+    /// `opt.contains(&x)` does the same as `opt.as_ref().is_some_and(|v| v == &x)`.
+    ///
+    /// [`is_some_and`]: Option::is_some_and
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(option_result_contains_value)]
+    ///
+    /// let x: Option<u32> = Some(2);
+    /// assert_eq!(x.contains(&2), true);
+    ///
+    /// let x: Option<u32> = Some(3);
+    /// assert_eq!(x.contains(&2), false);
+    ///
+    /// let x: Option<u32> = None;
+    /// assert_eq!(x.contains(&2), false);
+    ///
+    /// let x: Option<String> = Some("hi".to_string());
+    /// assert_eq!(x.contains("hi"), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "option_result_contains_value", issue = "none")]
+    pub fn contains<U: ?Sized>(&self, x: &U) -> bool
+    where
+        T: PartialEq<U>,
+    {
+        match self {
+            Some(y) => y == x,
+            None => false,
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Adapter for working with references
     /////////////////////////////////////////////////////////////////////////
@@ -609,6 +609,74 @@ pub fn is_err_and(self, f: impl FnOnce(E) -> bool) -> bool {
         }
     }
 
+    /// Returns `true` if the result is an [`Ok`] value containing the given value.
+    ///
+    /// This is a shorthand for [`is_ok_and`] with an equality check:
+    /// `res.contains(&x)` does the same as `res.as_ref().is_ok_and(|v| v == &x)`.
+    ///
+    /// [`is_ok_and`]: Result::is_ok_and
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(option_result_contains_value)]
+    ///
+    /// let x: Result<u32, &str> = Ok(2);
+    /// assert_eq!(x.contains(&2), true);
+    ///
+    /// let x: Result<u32, &str> = Ok(3);
+    /// assert_eq!(x.contains(&2), false);
+    ///
+    /// let x: Result<u32, &str> = Err("Some error message");
+    /// assert_eq!(x.contains(&2), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "option_result_contains_value", issue = "none")]
+    pub fn contains<U: ?Sized>(&self, x: &U) -> bool
+    where
+        T: PartialEq<U>,
+    {
+        match self {
+            Ok(y) => y == x,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the result is an [`Err`] value containing the given value.
+    ///
+    /// This is a shorthand for [`is_err_and`] with an equality check:
+    /// `res.contains_err(&e)` does the same as `res.as_ref().is_err_and(|v| v == &e)`.
+    ///
+    /// [`is_err_and`]: Result::is_err_and
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(result_contains_err_value)]
+    ///
+    /// let x: Result<u32, &str> = Ok(2);
+    /// assert_eq!(x.contains_err(&"Some error message"), false);
+    ///
+    /// let x: Result<u32, &str> = Err("Some error message");
+    /// assert_eq!(x.contains_err(&"Some error message"), true);
+    ///
+    /// let x: Result<u32, String> = Err("Some other error message".to_string());
+    /// assert_eq!(x.contains_err("Some error message"), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "result_contains_err_value", issue = "none")]
+    pub fn contains_err<F: ?Sized>(&self, f: &F) -> bool
+    where
+        E: PartialEq<F>,
+    {
+        match self {
+            Ok(_) => false,
+            Err(e) => e == f,
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Adapter for each variant
     /////////////////////////////////////////////////////////////////////////
@@ -1673,8 +1741,12 @@ impl<T, E> Result<Result<T, E>, E> {
     /// ```
     #[inline]
     #[unstable(feature = "result_flattening", issue = "70142")]
-    pub fn flatten(self) -> Result<T, E> {
-        self.and_then(convert::identity)
+    #[rustc_const_unstable(feature = "const_result", issue = "82814")]
+    pub const fn flatten(self) -> Result<T, E> {
+        match self {
+            Ok(inner) => inner,
+            Err(e) => Err(e),
+        }
     }
 }
 
@@ -183,6 +183,20 @@ fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
                 NonZeroUsize::new(n - advance).map_or(Ok(()), Err)
             }
 
+            // We override the default implementation so that the length is
+            // checked once up front instead of once per element.
+            #[inline]
+            fn next_chunk<const N: usize>(
+                &mut self,
+            ) -> Result<[$elem; N], crate::array::IntoIter<$elem, N>> {
+                if len!(self) < N {
+                    return crate::array::iter_next_chunk(self);
+                }
+                // SAFETY: we just checked that there are at least `N` elements
+                // left, so each of the `N` calls to `next_unchecked!` is in bounds.
+                Ok(crate::array::from_fn(|_| unsafe { next_unchecked!(self) }))
+            }
+
             #[inline]
             fn last(mut self) -> Option<$elem> {
                 self.next_back()
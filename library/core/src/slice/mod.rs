@@ -2659,6 +2659,16 @@ pub fn sort_unstable(&mut self)
     /// assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0]);
     /// ```
     ///
+    /// If the slice may contain a `NaN`, [`f64::total_cmp`] provides a total order instead, with
+    /// positive `NaN`s sorted after every other value:
+    ///
+    /// ```
+    /// let mut floats = [5f64, f64::NAN, 1.0, -0.0, 0.0];
+    /// floats.sort_unstable_by(f64::total_cmp);
+    /// assert_eq!(&floats[..4], [-0.0, 0.0, 1.0, 5.0]);
+    /// assert!(floats[4].is_nan());
+    /// ```
+    ///
     /// # Current implementation
     ///
     /// The current algorithm is based on [pattern-defeating quicksort][pdqsort] by Orson Peters,
@@ -16,6 +16,7 @@
 use self::pattern::Pattern;
 use self::pattern::{DoubleEndedSearcher, ReverseSearcher, Searcher};
 
+use crate::ascii;
 use crate::char::{self, EscapeDebugExtArgs};
 use crate::mem;
 use crate::slice::{self, SliceIndex};
@@ -2374,6 +2375,27 @@ pub fn is_ascii(&self) -> bool {
         self.as_bytes().is_ascii()
     }
 
+    /// If this string slice [`is_ascii`](Self::is_ascii), returns it as a slice
+    /// of [ASCII characters](`ascii::Char`), otherwise returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii::Char;
+    ///
+    /// let hi = [Char::CapitalH, Char::SmallI, Char::ExclamationMark];
+    /// assert_eq!("Hi!".as_ascii(), Some(&hi[..]));
+    /// assert_eq!("¡Hola!".as_ascii(), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn as_ascii(&self) -> Option<&[ascii::Char]> {
+        // Like in `is_ascii`, we can work on the bytes directly.
+        self.as_bytes().as_ascii()
+    }
+
     /// Checks that two strings are an ASCII case-insensitive match.
     ///
     /// Same as `to_ascii_lowercase(a) == to_ascii_lowercase(b)`,
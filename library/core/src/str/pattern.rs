@@ -31,6 +31,86 @@
 //! ```
 //!
 //! [pattern-impls]: Pattern#implementors
+//!
+//! # Implementing a custom pattern
+//!
+//! A pattern type provides a [`Searcher`] that breaks the haystack into
+//! [`SearchStep`]s. Implementing [`ReverseSearcher`] as well makes methods like
+//! [`str::rfind`] and [`str::rsplit`] available, and [`DoubleEndedSearcher`]
+//! additionally lets iterators such as [`str::split`] be consumed from both
+//! ends. Only add the latter when searching from either end finds exactly the
+//! same matches; for `&str` this is not the case, as `"aaa".split("aa")`
+//! and `"aaa".rsplit("aa")` show.
+//!
+//! ```
+//! #![feature(pattern)]
+//!
+//! use std::str::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, SearchStep, Searcher};
+//!
+//! /// Matches any ASCII digit.
+//! struct AsciiDigit;
+//!
+//! struct AsciiDigitSearcher<'a> {
+//!     haystack: &'a str,
+//!     // The range that has not been searched yet, always on char boundaries.
+//!     front: usize,
+//!     back: usize,
+//! }
+//!
+//! impl<'a> Pattern<'a> for AsciiDigit {
+//!     type Searcher = AsciiDigitSearcher<'a>;
+//!
+//!     fn into_searcher(self, haystack: &'a str) -> Self::Searcher {
+//!         AsciiDigitSearcher { haystack, front: 0, back: haystack.len() }
+//!     }
+//! }
+//!
+//! fn step(c: char, start: usize, end: usize) -> SearchStep {
+//!     if c.is_ascii_digit() {
+//!         SearchStep::Match(start, end)
+//!     } else {
+//!         SearchStep::Reject(start, end)
+//!     }
+//! }
+//!
+//! // SAFETY: each step covers exactly one char of the unsearched range, so the
+//! // steps are adjacent, non-overlapping and lie on char boundaries.
+//! unsafe impl<'a> Searcher<'a> for AsciiDigitSearcher<'a> {
+//!     fn haystack(&self) -> &'a str {
+//!         self.haystack
+//!     }
+//!
+//!     fn next(&mut self) -> SearchStep {
+//!         let Some(c) = self.haystack[self.front..self.back].chars().next() else {
+//!             return SearchStep::Done;
+//!         };
+//!         let start = self.front;
+//!         self.front += c.len_utf8();
+//!         step(c, start, self.front)
+//!     }
+//! }
+//!
+//! // SAFETY: as above, walking back from the end of the unsearched range.
+//! unsafe impl<'a> ReverseSearcher<'a> for AsciiDigitSearcher<'a> {
+//!     fn next_back(&mut self) -> SearchStep {
+//!         let Some(c) = self.haystack[self.front..self.back].chars().next_back() else {
+//!             return SearchStep::Done;
+//!         };
+//!         let end = self.back;
+//!         self.back -= c.len_utf8();
+//!         step(c, self.back, end)
+//!     }
+//! }
+//!
+//! // Every char is judged on its own, so both directions agree.
+//! impl<'a> DoubleEndedSearcher<'a> for AsciiDigitSearcher<'a> {}
+//!
+//! let s = "r2d2 ü 42";
+//! assert_eq!(s.find(AsciiDigit), Some(1));
+//! assert_eq!(s.rfind(AsciiDigit), Some(s.len() - 1));
+//! assert_eq!(s.trim_end_matches(AsciiDigit), "r2d2 ü ");
+//! assert_eq!(s.split(AsciiDigit).rev().collect::<Vec<_>>(), ["", "", " ü ", "d", "r"]);
+//! ```
 
 #![unstable(
     feature = "pattern",
@@ -65,6 +145,8 @@
 /// |--------------------------|-------------------------------------------|
 /// | `&str`                   | is substring                              |
 /// | `char`                   | is contained in string                    |
+/// | `[char; N]`              | any char in array is contained in string  |
+/// | `&[char; N]`             | any char in array is contained in string  |
 /// | `&[char]`                | any char in slice is contained in string  |
 /// | `F: FnMut(char) -> bool` | `F` returns `true` for a char in string   |
 /// | `&&str`                  | is substring                              |
@@ -167,6 +167,33 @@ fn iterator_debug() {
     assert_eq!(format!("{:?}", IntoIterator::into_iter(arr)), "IntoIter([0, 1, 2, 5, 9])",);
 }
 
+#[test]
+fn iterator_as_slice() {
+    let mut it = IntoIterator::into_iter([1, 2, 3, 4, 5]);
+    assert_eq!(it.as_slice(), &[1, 2, 3, 4, 5]);
+    it.next();
+    it.next_back();
+    assert_eq!(it.as_slice(), &[2, 3, 4]);
+    it.as_mut_slice()[1] = 30;
+    assert_eq!(it.collect::<Vec<_>>(), [2, 30, 4]);
+}
+
+#[test]
+fn iterator_constructors() {
+    use core::mem::MaybeUninit;
+
+    let mut it = array::IntoIter::<String, 3>::empty();
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+
+    // Only the middle slot is initialized, so only it may be yielded or dropped.
+    let mut buffer = [const { MaybeUninit::<String>::uninit() }; 3];
+    buffer[1].write(String::from("middle"));
+    let it = unsafe { array::IntoIter::new_unchecked(buffer, 1..2) };
+    assert_eq!(it.as_slice(), ["middle"]);
+    assert_eq!(it.collect::<Vec<_>>(), ["middle"]);
+}
+
 #[test]
 fn iterator_drops() {
     use core::cell::Cell;
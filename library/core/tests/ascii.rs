@@ -1,3 +1,4 @@
+use core::ascii::Char as AsciiChar;
 use core::char::from_u32;
 
 #[test]
@@ -479,3 +480,90 @@ pub fn run() {
         is_ascii_control      => [false, false, false, false, false];
     }
 }
+
+#[test]
+fn ascii_char_conversions() {
+    for b in 0..=u8::MAX {
+        match AsciiChar::from_u8(b) {
+            Some(c) => {
+                assert!(b.is_ascii());
+                assert_eq!(c.to_u8(), b);
+                assert_eq!(u8::from(c), b);
+                assert_eq!(char::from(c), b as char);
+                assert_eq!(c.as_str(), (b as char).to_string());
+                assert_eq!(b.as_ascii(), Some(c));
+                assert_eq!((b as char).as_ascii(), Some(c));
+            }
+            None => {
+                assert!(!b.is_ascii());
+                assert_eq!(b.as_ascii(), None);
+            }
+        }
+    }
+    assert_eq!('é'.as_ascii(), None);
+    assert_eq!('\u{10FFFF}'.as_ascii(), None);
+
+    assert_eq!(AsciiChar::digit(0), Some(AsciiChar::Digit0));
+    assert_eq!(AsciiChar::digit(9), Some(AsciiChar::Digit9));
+    assert_eq!(AsciiChar::digit(10), None);
+}
+
+#[test]
+fn ascii_char_slices() {
+    let hello = b"Hello, world!".as_ascii().unwrap();
+    assert_eq!(hello.as_str(), "Hello, world!");
+    assert_eq!(hello.as_bytes(), b"Hello, world!");
+    assert_eq!(hello[0], AsciiChar::CapitalH);
+    assert_eq!("Hello, world!".as_ascii(), Some(hello));
+
+    assert_eq!(b"\x80".as_ascii(), None);
+    assert_eq!("Grüße".as_ascii(), None);
+    assert_eq!(b"".as_ascii(), Some(&[][..]));
+}
+
+#[test]
+fn ascii_char_classification_matches_u8() {
+    for c in (0..=127).map(|b| AsciiChar::from_u8(b).unwrap()) {
+        let b = c.to_u8();
+        assert_eq!(c.is_alphabetic(), b.is_ascii_alphabetic());
+        assert_eq!(c.is_uppercase(), b.is_ascii_uppercase());
+        assert_eq!(c.is_lowercase(), b.is_ascii_lowercase());
+        assert_eq!(c.is_alphanumeric(), b.is_ascii_alphanumeric());
+        assert_eq!(c.is_digit(), b.is_ascii_digit());
+        assert_eq!(c.is_hexdigit(), b.is_ascii_hexdigit());
+        assert_eq!(c.is_punctuation(), b.is_ascii_punctuation());
+        assert_eq!(c.is_graphic(), b.is_ascii_graphic());
+        assert_eq!(c.is_whitespace(), b.is_ascii_whitespace());
+        assert_eq!(c.is_control(), b.is_ascii_control());
+        assert_eq!(c.to_uppercase().to_u8(), b.to_ascii_uppercase());
+        assert_eq!(c.to_lowercase().to_u8(), b.to_ascii_lowercase());
+        assert!(c.eq_ignore_case(&c.to_uppercase()));
+    }
+
+    let mut c = AsciiChar::SmallQ;
+    c.make_uppercase();
+    assert_eq!(c, AsciiChar::CapitalQ);
+    c.make_lowercase();
+    assert_eq!(c, AsciiChar::SmallQ);
+}
+
+#[test]
+fn ascii_char_const() {
+    const A: Option<AsciiChar> = AsciiChar::from_u8(b'A');
+    const IS_ALPHA: bool = AsciiChar::CapitalA.is_alphabetic();
+    const SEVEN: Option<AsciiChar> = b'7'.as_ascii();
+    const STR: &str = AsciiChar::Tilde.as_str();
+    assert_eq!(A, Some(AsciiChar::CapitalA));
+    assert!(IS_ALPHA);
+    assert_eq!(SEVEN, Some(AsciiChar::Digit7));
+    assert_eq!(STR, "~");
+}
+
+#[test]
+fn ascii_char_fmt() {
+    assert_eq!(format!("{}", AsciiChar::SmallA), "a");
+    assert_eq!(format!("{:>3}", AsciiChar::Asterisk), "  *");
+    assert_eq!(format!("{:?}", AsciiChar::SmallA), "'a'");
+    assert_eq!(format!("{:?}", AsciiChar::Null), "'\\0'");
+    assert_eq!(format!("{:?}", AsciiChar::Apostrophe), "'\\''");
+}
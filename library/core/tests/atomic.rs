@@ -312,3 +312,64 @@ fn atomic_const_from() {
     const _ATOMIC_BOOL: AtomicBool = AtomicBool::from(true);
     const _ATOMIC_PTR: AtomicPtr<u32> = AtomicPtr::from(core::ptr::null_mut());
 }
+
+macro_rules! fixed_width_tests {
+    ($cfg:literal, $mod:ident, $Atomic:ident, $int:ident) => {
+        #[cfg(target_has_atomic = $cfg)]
+        mod $mod {
+            use core::sync::atomic::Ordering::SeqCst;
+            use core::sync::atomic::$Atomic;
+
+            static S: $Atomic = $Atomic::new($int::MAX);
+
+            #[test]
+            fn static_new_is_const() {
+                assert_eq!(S.load(SeqCst), $int::MAX);
+            }
+
+            #[test]
+            fn add_sub_wrap_at_width() {
+                let x = $Atomic::new($int::MAX);
+                assert_eq!(x.fetch_add(1, SeqCst), $int::MAX);
+                assert_eq!(x.load(SeqCst), $int::MIN);
+                assert_eq!(x.fetch_sub(1, SeqCst), $int::MIN);
+                assert_eq!(x.load(SeqCst), $int::MAX);
+            }
+
+            // Missing intrinsic in compiler-builtins
+            #[test]
+            #[cfg(any(not(target_arch = "arm"), target_os = "linux"))]
+            fn min_max_use_full_range() {
+                let x = $Atomic::new(0);
+                assert_eq!(x.fetch_max($int::MAX, SeqCst), 0);
+                assert_eq!(x.fetch_min($int::MIN, SeqCst), $int::MAX);
+                assert_eq!(x.load(SeqCst), $int::MIN);
+            }
+
+            #[test]
+            fn bitops_and_update() {
+                let x = $Atomic::new(0b0101);
+                assert_eq!(x.fetch_or(0b1010, SeqCst), 0b0101);
+                assert_eq!(x.fetch_and(0b0110, SeqCst), 0b1111);
+                assert_eq!(x.fetch_xor(0b0011, SeqCst), 0b0110);
+                assert_eq!(x.swap(!0, SeqCst), 0b0101);
+                assert_eq!(x.fetch_nand(0b1000, SeqCst), !0);
+                assert_eq!(x.load(SeqCst), !0b1000);
+                let old = x.fetch_update(SeqCst, SeqCst, |v| Some(v.wrapping_add(9)));
+                assert_eq!(old, Ok(!0b1000));
+                assert_eq!(x.load(SeqCst), 0);
+                assert_eq!(x.fetch_update(SeqCst, SeqCst, |_| None), Err(0));
+                assert_eq!(x.into_inner(), 0);
+            }
+        }
+    };
+}
+
+fixed_width_tests!("8", atomic_u8, AtomicU8, u8);
+fixed_width_tests!("8", atomic_i8, AtomicI8, i8);
+fixed_width_tests!("16", atomic_u16, AtomicU16, u16);
+fixed_width_tests!("16", atomic_i16, AtomicI16, i16);
+fixed_width_tests!("32", atomic_u32, AtomicU32, u32);
+fixed_width_tests!("32", atomic_i32, AtomicI32, i32);
+fixed_width_tests!("64", atomic_u64, AtomicU64, u64);
+fixed_width_tests!("64", atomic_i64, AtomicI64, i64);
@@ -39,6 +39,22 @@ fn test_ord_max_min() {
     assert_eq!(1.min(1), 1);
 }
 
+#[test]
+fn test_ord_clamp() {
+    assert_eq!((-3).clamp(-2, 1), -2);
+    assert_eq!(0.clamp(-2, 1), 0);
+    assert_eq!(2.clamp(-2, 1), 1);
+    assert_eq!(1.clamp(1, 1), 1);
+    assert_eq!("b".clamp("a", "c"), "b");
+    assert_eq!("z".clamp("a", "c"), "c");
+}
+
+#[test]
+#[should_panic]
+fn test_ord_clamp_min_greater_than_max() {
+    let _ = 1.clamp(3, 1);
+}
+
 #[test]
 fn test_ord_min_max_by() {
     let f = |x: &i32, y: &i32| x.abs().cmp(&y.abs());
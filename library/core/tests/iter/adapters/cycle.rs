@@ -29,3 +29,12 @@ fn test_cycle() {
     iter.nth(9);
     assert_eq!(iter.take(3).sum::<i32>(), 3);
 }
+
+#[test]
+fn test_cycle_trusted_len() {
+    fn assert_trusted_len<T: TrustedLen>(_: &T) {}
+    let it = (0..3).cycle();
+    assert_trusted_len(&it);
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+    assert_eq!((0..0).cycle().size_hint(), (0, Some(0)));
+}
@@ -0,0 +1,68 @@
+use core::iter::*;
+
+#[test]
+fn test_cycle_n() {
+    let it = (0..3).cycle_n(4);
+    assert_eq!(it.size_hint(), (12, Some(12)));
+    assert_eq!(it.clone().collect::<Vec<_>>(), [0, 1, 2, 0, 1, 2, 0, 1, 2, 0, 1, 2]);
+    assert_eq!(it.fold(0, |acc, x| acc + x), 12);
+
+    let mut it = (0..3).cycle_n(2);
+    assert_eq!(it.size_hint(), (6, Some(6)));
+    assert_eq!(it.nth(3), Some(0));
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+
+    let mut it = (0..3).cycle_n(0);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+
+    let mut it = (0..0).cycle_n(usize::MAX);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+    assert_eq!(empty::<i32>().cycle_n(usize::MAX).fold(0, |acc, x| acc + x), 0);
+}
+
+#[test]
+fn test_cycle_n_size_hint() {
+    assert_eq!((0..10).filter(|_| true).cycle_n(3).size_hint(), (0, Some(30)));
+    assert_eq!((0..).cycle_n(3).size_hint(), (usize::MAX, None));
+    assert_eq!((0..2).cycle_n(usize::MAX).size_hint(), (usize::MAX, None));
+
+    let mut it = "abc".chars().cycle_n(2);
+    it.next();
+    assert_eq!(it.size_hint(), (2, Some(5)));
+}
+
+#[test]
+fn test_cycle_n_try_fold() {
+    let mut it = (0..4).cycle_n(3);
+    // stop partway through the second pass
+    let r = it.try_fold(0, |acc, x| if acc + x > 7 { Err(acc) } else { Ok(acc + x) });
+    assert_eq!(r, Err(7));
+    assert_eq!(it.size_hint(), (5, Some(5)));
+    assert_eq!(it.next(), Some(3));
+    assert_eq!(it.try_fold(0, |acc, x| Some(acc + x)), Some(6));
+    assert_eq!(it.next(), None);
+
+    let mut it = (0..4).cycle_n(3);
+    assert_eq!(it.position(|x| x == 3), Some(3));
+    assert_eq!(it.position(|x| x == 3), Some(3));
+    assert_eq!(it.position(|x| x == 3), Some(3));
+    assert_eq!(it.position(|x| x == 3), None);
+}
+
+#[test]
+fn test_cycle_n_trusted_len() {
+    fn assert_trusted_len<T: TrustedLen>(_: &T) {}
+    let it = [1, 2].iter().cycle_n(3);
+    assert_trusted_len(&it);
+
+    let v: Vec<_> = it.copied().collect();
+    assert_eq!(v, [1, 2, 1, 2, 1, 2]);
+    assert_eq!(v.capacity(), 6);
+}
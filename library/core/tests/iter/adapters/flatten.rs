@@ -171,6 +171,27 @@ fn assert_trusted_len<T: TrustedLen>(_: &T) {}
     assert_eq!(iter.size_hint(), (20, Some(20)));
 }
 
+#[test]
+fn test_flatten_option_size_hint() {
+    let mut iter = [Some(1), None, Some(3), None].into_iter().flatten();
+    assert_eq!(iter.size_hint(), (0, Some(4)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.size_hint(), (0, Some(1)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let iter = (0..10).flat_map(|x| if x % 2 == 0 { Ok(x) } else { Err(x) });
+    assert_eq!(iter.size_hint(), (0, Some(10)));
+
+    let v = [Some(1), None];
+    assert_eq!(v.iter().flatten().size_hint(), (0, Some(2)));
+
+    let iter = (0..).flat_map(Some);
+    assert_eq!(iter.size_hint(), (0, None));
+}
+
 #[test]
 fn test_flatten_count() {
     let mut it = once(0..10).chain(once(10..30)).chain(once(30..40)).flatten();
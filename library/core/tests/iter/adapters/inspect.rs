@@ -36,3 +36,12 @@ fn test_inspect_fold() {
     }
     assert_eq!(n, xs.len());
 }
+
+#[test]
+fn test_inspect_trusted_len() {
+    fn assert_trusted_len<T: TrustedLen>(_: &T) {}
+    let mut n = 0;
+    let it = (0..10).inspect(|_| n += 1);
+    assert_trusted_len(&it);
+    assert_eq!(it.size_hint(), (10, Some(10)));
+}
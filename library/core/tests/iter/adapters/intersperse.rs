@@ -152,3 +152,15 @@ fn test_try_fold_specialization_intersperse_err() {
     iter.try_for_each(|item| if item == "b" { None } else { Some(()) });
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn test_intersperse_fused() {
+    fn assert_fused<T: FusedIterator>(_: &T) {}
+    let mut it = [1, 2].into_iter().intersperse(0);
+    assert_fused(&it);
+    assert_eq!(it.by_ref().collect::<Vec<_>>(), [1, 0, 2]);
+    assert_eq!(it.next(), None);
+
+    let it = [1, 2].into_iter().intersperse_with(|| 0);
+    assert_fused(&it);
+}
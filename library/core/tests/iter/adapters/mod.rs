@@ -4,6 +4,7 @@
 mod cloned;
 mod copied;
 mod cycle;
+mod cycle_n;
 mod enumerate;
 mod filter;
 mod filter_map;
@@ -21,6 +22,7 @@
 mod take;
 mod take_while;
 mod zip;
+mod zip_all;
 
 use core::cell::Cell;
 
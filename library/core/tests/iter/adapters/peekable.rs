@@ -270,3 +270,16 @@ fn test_peekable_non_fused() {
     assert_eq!(iter.peek(), None);
     assert_eq!(iter.next_back(), None);
 }
+
+#[test]
+fn test_peekable_is_empty() {
+    let mut it = [1, 2].into_iter().peekable();
+    assert!(!it.is_empty());
+    assert_eq!(it.peek(), Some(&1));
+    assert!(!it.is_empty());
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), Some(2));
+    assert!(it.is_empty());
+    assert_eq!(it.peek(), None);
+    assert!(it.is_empty());
+}
@@ -134,6 +134,34 @@ fn test_iterator_step_by_nth_back() {
     assert_eq!(it().nth_back(42), None);
 }
 
+#[test]
+fn test_iterator_step_by_rev() {
+    for n in 0..20 {
+        for step in 1..8 {
+            let mut forward: Vec<_> = (0..n).step_by(step).collect();
+            forward.reverse();
+            let backward: Vec<_> = (0..n).step_by(step).rev().collect();
+            assert_eq!(backward, forward, "n = {n}, step = {step}");
+            let rfolded = (0..n).step_by(step).rfold(Vec::new(), |mut v, x| {
+                v.push(x);
+                v
+            });
+            assert_eq!(rfolded, forward, "n = {n}, step = {step}");
+        }
+    }
+
+    // Alternating ends must agree on where the trailing partial step is.
+    let mut it = (0..23).step_by(4);
+    assert_eq!(it.next_back(), Some(20));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next_back(), Some(16));
+    assert_eq!(it.next(), Some(4));
+    assert_eq!(it.next_back(), Some(12));
+    assert_eq!(it.next(), Some(8));
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+}
+
 #[test]
 fn test_iterator_step_by_nth_try_rfold() {
     let mut it = (0..100).step_by(10);
@@ -244,3 +272,13 @@ fn test_step_by_skip() {
     assert_eq!((0..=50).step_by(10).nth(3), Some(30));
     assert_eq!((200..=255u8).step_by(10).nth(3), Some(230));
 }
+
+#[test]
+fn test_step_by_fused() {
+    fn assert_fused<T: FusedIterator>(_: &T) {}
+    let mut it = (0..5).step_by(2);
+    assert_fused(&it);
+    assert_eq!(it.by_ref().count(), 3);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
@@ -167,3 +167,15 @@ fn test_byref_take_consumed_items() {
     assert_eq!(count, 70);
     assert_eq!(inner, 90..90);
 }
+
+#[test]
+fn test_take_is_empty() {
+    let mut it = (0..10).take(2);
+    assert!(!it.is_empty());
+    it.next();
+    it.next();
+    assert!(it.is_empty());
+
+    assert!((0..10).take(0).is_empty());
+    assert!((0..0).take(5).is_empty());
+}
@@ -271,6 +271,24 @@ fn test_double_ended_zip() {
     assert_eq!(it.next(), None);
 }
 
+#[test]
+fn test_zip_is_empty() {
+    // `TrustedRandomAccess` on both sides.
+    let mut it = [1, 2, 3].iter().zip(&[4, 5]);
+    assert!(!it.is_empty());
+    it.next();
+    assert!(!it.is_empty());
+    it.next();
+    assert!(it.is_empty());
+    assert!((0..5).zip(0..0).is_empty());
+
+    // Neither side supports random access.
+    let mut it = [1, 2].into_iter().zip(once(3));
+    assert!(!it.is_empty());
+    it.next();
+    assert!(it.is_empty());
+}
+
 #[test]
 fn test_issue_82282() {
     fn overflowed_zip(arr: &[i32]) -> impl Iterator<Item = (i32, &())> {
@@ -0,0 +1,70 @@
+use super::*;
+use core::iter::OneOrBoth::{Both, Left, Right};
+use core::iter::*;
+
+#[test]
+fn test_zip_all() {
+    let it = [1, 2, 3].into_iter().zip_all(['a', 'b']);
+    assert_eq!(it.collect::<Vec<_>>(), [Both(1, 'a'), Both(2, 'b'), Left(3)]);
+
+    let it = [1].into_iter().zip_all(['a', 'b', 'c']);
+    assert_eq!(it.collect::<Vec<_>>(), [Both(1, 'a'), Right('b'), Right('c')]);
+
+    let it = [1, 2].into_iter().zip_all(['a', 'b']);
+    assert_eq!(it.collect::<Vec<_>>(), [Both(1, 'a'), Both(2, 'b')]);
+
+    let mut it = empty::<i32>().zip_all(empty::<char>());
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_zip_all_size_hint() {
+    let mut it = (0..5).zip_all(0..2);
+    assert_eq!(it.size_hint(), (5, Some(5)));
+    assert_eq!(it.len(), 5);
+    it.next();
+    it.next();
+    it.next();
+    assert_eq!(it.size_hint(), (2, Some(2)));
+
+    let it = (0..5).zip_all((0..10).filter(|x| x % 2 == 0));
+    assert_eq!(it.size_hint(), (5, Some(10)));
+
+    let it = (0..5).zip_all(0..);
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn test_zip_all_next_back() {
+    let mut it = [1, 2, 3, 4].into_iter().zip_all(['a', 'b']);
+    assert_eq!(it.next_back(), Some(Left(4)));
+    assert_eq!(it.next(), Some(Both(1, 'a')));
+    assert_eq!(it.next_back(), Some(Left(3)));
+    assert_eq!(it.next_back(), Some(Both(2, 'b')));
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+
+    let fwd: Vec<_> = (0..3).zip_all(10..17).collect();
+    let mut back: Vec<_> = (0..3).zip_all(10..17).rev().collect();
+    back.reverse();
+    assert_eq!(fwd, back);
+}
+
+#[test]
+fn test_zip_all_fuses_inner() {
+    let a = NonFused::new([1, 2].into_iter());
+    let b = NonFused::new([3].into_iter());
+    let mut it = a.zip_all(b);
+    assert_eq!(it.next(), Some(Both(1, 3)));
+    assert_eq!(it.next(), Some(Left(2)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_one_or_both() {
+    assert_eq!(Both(1, 'a').left(), Some(1));
+    assert_eq!(Both(1, 'a').right(), Some('a'));
+    assert_eq!(Left::<_, char>(1).right(), None);
+    assert_eq!(Right::<i32, _>('a').left(), None);
+}
@@ -68,6 +68,27 @@ fn test_range_exhaustion() {
     assert_eq!(r, 100..10);
 }
 
+#[test]
+fn test_range_exact_size_is_empty() {
+    fn is_empty<I: ExactSizeIterator>(it: &I) -> bool {
+        it.is_empty()
+    }
+
+    let mut r = 0..2;
+    assert!(!is_empty(&r));
+    r.next();
+    r.next();
+    assert!(is_empty(&r));
+    assert!(is_empty(&(5..3)));
+
+    let mut r = 0u8..=1;
+    assert!(!is_empty(&r));
+    r.next();
+    r.next();
+    assert!(is_empty(&r));
+    assert!(is_empty(&(5u8..=3)));
+}
+
 #[test]
 fn test_range_inclusive_exhaustion() {
     let mut r = 10..=10;
@@ -347,6 +368,31 @@ fn test_range_inclusive_last_max() {
     assert_eq!(r.max(), None);
 }
 
+#[test]
+fn test_range_count() {
+    assert_eq!((0..20).count(), 20);
+    assert_eq!((-20..0i8).count(), 20);
+    assert_eq!((5..5).count(), 0);
+    assert_eq!((5..1).count(), 0);
+    assert_eq!((0..usize::MAX).count(), usize::MAX);
+    assert_eq!(('a'..'z').count(), 25);
+
+    assert_eq!((0..=20).count(), 21);
+    assert_eq!((i8::MIN..=i8::MAX).count(), 256);
+    assert_eq!((5..=1).count(), 0);
+    let mut r = 10..=10;
+    assert_eq!(r.clone().count(), 1);
+    r.next();
+    assert_eq!(r.count(), 0);
+    assert_eq!(('\u{D7FF}'..='\u{E000}').count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "count overflowed usize")]
+fn test_range_inclusive_count_overflow() {
+    (0..=usize::MAX).count();
+}
+
 #[test]
 fn test_range_min() {
     assert_eq!((0..20).min(), Some(0));
@@ -426,6 +472,19 @@ fn test_range_size_hint() {
     assert_eq!((imin..imax + 1).size_hint(), (usize::MAX, None));
 }
 
+#[test]
+fn test_range_u64_trusted_len() {
+    fn assert_trusted_len<T: TrustedLen>(_: &T) {}
+
+    let it = 0..100u64;
+    assert_trusted_len(&it);
+    assert_eq!(it.size_hint(), (100, Some(100)));
+
+    let it = (-50..50i64).zip(0..1000u64);
+    assert_trusted_len(&it);
+    assert_eq!(it.size_hint(), (100, Some(100)));
+}
+
 #[test]
 fn test_range_inclusive_size_hint() {
     assert_eq!((1..=0usize).size_hint(), (0, Some(0)));
@@ -1,4 +1,5 @@
 use core::iter::*;
+use core::num::{NonZeroI32, NonZeroU16, NonZeroU8};
 
 #[test]
 fn test_iterator_sum() {
@@ -64,3 +65,91 @@ fn test_iterator_product_option() {
     let v: &[Option<i32>] = &[Some(1), None, Some(3), Some(4)];
     assert_eq!(v.iter().cloned().product::<Option<i32>>(), None);
 }
+
+#[test]
+fn test_iterator_sum_product_saturating() {
+    use core::num::Saturating;
+
+    let v = [Saturating(200u8), Saturating(100), Saturating(3)];
+    assert_eq!(v.iter().sum::<Saturating<u8>>(), Saturating(u8::MAX));
+    assert_eq!(v.iter().copied().product::<Saturating<u8>>(), Saturating(u8::MAX));
+    assert_eq!(v[2..].iter().sum::<Saturating<u8>>(), Saturating(3));
+
+    let v = [Saturating(i32::MIN), Saturating(-1), Saturating(5)];
+    assert_eq!(v.into_iter().sum::<Saturating<i32>>(), Saturating(i32::MIN + 5));
+    assert_eq!(v.iter().product::<Saturating<i32>>(), Saturating(i32::MAX));
+    assert_eq!(empty::<Saturating<i32>>().sum::<Saturating<i32>>(), Saturating(0));
+    assert_eq!(empty::<Saturating<i32>>().product::<Saturating<i32>>(), Saturating(1));
+}
+
+#[test]
+fn test_iterator_product_nonzero() {
+    let nz = |x| NonZeroU8::new(x).unwrap();
+    let v = [nz(2), nz(3), nz(7)];
+    assert_eq!(v.iter().product::<NonZeroU8>(), nz(42));
+    assert_eq!(v.into_iter().product::<NonZeroU8>(), nz(42));
+    assert_eq!(v[..0].iter().product::<NonZeroU8>(), nz(1));
+
+    let v = [NonZeroI32::new(-3).unwrap(), NonZeroI32::new(5).unwrap()];
+    assert_eq!(v.iter().product::<NonZeroI32>(), NonZeroI32::new(-15).unwrap());
+}
+
+#[test]
+#[should_panic = "attempt to multiply with overflow"]
+fn test_iterator_product_nonzero_overflow() {
+    // The wrapped product would be zero.
+    let v = [NonZeroU16::new(256).unwrap(); 2];
+    v.iter().product::<NonZeroU16>();
+}
+
+#[test]
+fn test_iterator_checked_sum() {
+    let v: &[u8] = &[100, 50, 20, 200];
+    assert_eq!(v[..3].iter().checked_sum::<u8>(), Some(170));
+    assert_eq!(v[..3].iter().copied().checked_sum::<u8>(), Some(170));
+    assert_eq!(v.iter().checked_sum::<u8>(), None);
+    assert_eq!(v[..0].iter().checked_sum::<u8>(), Some(0));
+    assert_eq!([i32::MIN, -1].iter().checked_sum::<i32>(), None);
+
+    // stops at the first overflow
+    let mut it = v.iter().rev();
+    assert_eq!(it.by_ref().checked_sum::<u8>(), None);
+    assert_eq!(it.next(), Some(&100));
+}
+
+#[test]
+fn test_iterator_checked_product() {
+    let v: &[i32] = &[1, 2, 3, 4, i32::MAX];
+    assert_eq!(v[..4].iter().checked_product::<i32>(), Some(24));
+    assert_eq!(v[..4].iter().copied().checked_product::<i32>(), Some(24));
+    assert_eq!(v.iter().checked_product::<i32>(), None);
+    assert_eq!(v[..0].iter().checked_product::<i32>(), Some(1));
+    assert_eq!([0, i32::MAX, i32::MAX].iter().checked_product::<i32>(), Some(0));
+}
+
+#[test]
+fn test_iterator_sum_float() {
+    for len in [0usize, 1, 7, 8, 9, 255, 256, 257, 1000, 4096, 100_001] {
+        let v: Vec<f64> = (0..len).map(|i| i as f64).collect();
+        let expected = (len * len.saturating_sub(1) / 2) as f64;
+        assert_eq!(v.iter().sum::<f64>(), expected);
+        assert_eq!(v.iter().copied().sum::<f64>(), expected);
+        assert_eq!(v.iter().filter(|_| true).sum::<f64>(), expected);
+    }
+
+    assert!([1.0, f32::NAN, 2.0].iter().sum::<f32>().is_nan());
+    assert_eq!([1.0, f32::INFINITY].into_iter().sum::<f32>(), f32::INFINITY);
+    // the empty sum is positive zero, even for negative zero elements
+    assert!([-0.0f64].iter().sum::<f64>().is_sign_positive());
+    assert!([0.0f64; 0].into_iter().sum::<f64>().is_sign_positive());
+}
+
+#[test]
+fn test_iterator_sum_float_precision() {
+    // Adding 0.1 one million times sequentially drifts off by about 1%
+    // in single precision.
+    let v = vec![0.1f32; 1_000_000];
+    assert!((v.iter().sum::<f32>() - 100_000.0).abs() < 1.0);
+    assert!((v.iter().copied().sum::<f32>() - 100_000.0).abs() < 1.0);
+    assert!((v.iter().map(|&x| x).sum::<f32>() - 100_000.0).abs() < 1.0);
+}
@@ -89,3 +89,39 @@ fn test_rposition_panic() {
         false
     });
 }
+
+#[test]
+fn test_try_rfor_each() {
+    let xs = [1, 2, 3, 4, 5];
+    let mut seen = Vec::new();
+    let mut it = xs.iter();
+    let r = it.try_rfor_each(|&x| {
+        if x == 3 {
+            return Err(x);
+        }
+        seen.push(x);
+        Ok(())
+    });
+    assert_eq!(r, Err(3));
+    assert_eq!(seen, [5, 4]);
+    assert_eq!(it.as_slice(), &[1, 2]);
+
+    let mut sum = 0;
+    assert_eq!((1..=4).try_rfor_each(|x| Some(sum += x)), Some(()));
+    assert_eq!(sum, 10);
+}
+
+#[test]
+fn test_rfind_map() {
+    let xs = ["1", "two", "3", "four"];
+    let mut it = xs.iter();
+    assert_eq!(it.rfind_map(|s| s.parse::<i32>().ok()), Some(3));
+    assert_eq!(it.as_slice(), &["1", "two"]);
+    assert_eq!(it.rfind_map(|s| s.parse::<i32>().ok()), Some(1));
+    assert_eq!(it.rfind_map(|s| s.parse::<i32>().ok()), None);
+
+    // Forwards through `Rev`, so it searches from the front of the inner iterator.
+    let mut it = (1..10).rev();
+    assert_eq!(it.rfind_map(|x| (x % 4 == 0).then_some(x * 10)), Some(40));
+    assert_eq!(it.next_back(), Some(5));
+}
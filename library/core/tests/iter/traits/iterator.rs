@@ -589,6 +589,28 @@ fn test_next_chunk() {
     assert_eq!(it.next_chunk::<0>().unwrap(), []);
 }
 
+#[test]
+fn test_collect_array() {
+    assert_eq!((0..4).collect_array(), Some([0, 1, 2, 3]));
+    assert_eq!((0..0).collect_array(), Some([]));
+    assert_eq!((0..3).collect_array::<4>(), None);
+    assert_eq!((0..5).collect_array::<4>(), None);
+
+    // only a single element past the array length is consumed
+    let mut it = 0..10;
+    assert_eq!(it.by_ref().collect_array::<4>(), None);
+    assert_eq!(it.next(), Some(5));
+
+    // everything taken out of the iterator is dropped on failure
+    let counter = std::rc::Rc::new(());
+    let it = std::iter::repeat_with(|| counter.clone()).take(3);
+    assert!(it.collect_array::<5>().is_none());
+    assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+    let it = std::iter::repeat_with(|| counter.clone()).take(3);
+    assert!(it.collect_array::<2>().is_none());
+    assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+}
+
 // just tests by whether or not this compiles
 fn _empty_impl_all_auto_traits<T>() {
     use std::panic::{RefUnwindSafe, UnwindSafe};
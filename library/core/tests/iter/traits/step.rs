@@ -87,3 +87,24 @@ fn test_step_backward() {
         Some(i128::MIN)
     );
 }
+
+#[test]
+fn test_step_char() {
+    assert_eq!(Step::steps_between(&'a', &'z'), Some(25_usize));
+    assert_eq!(Step::steps_between(&'z', &'a'), None);
+    assert_eq!(Step::steps_between(&'\u{D7FF}', &'\u{E000}'), Some(1_usize));
+    assert_eq!(Step::steps_between(&'\0', &char::MAX), Some(0x10_FFFF - 0x800_usize));
+
+    assert_eq!(Step::forward_checked('a', 25_usize), Some('z'));
+    assert_eq!(Step::forward_checked('\u{D7FF}', 1_usize), Some('\u{E000}'));
+    assert_eq!(Step::forward_checked('\u{D7FE}', 3_usize), Some('\u{E001}'));
+    assert_eq!(Step::forward_checked(char::MAX, 1_usize), None);
+    assert_eq!(Step::forward_checked('\0', usize::MAX), None);
+
+    assert_eq!(Step::backward_checked('z', 25_usize), Some('a'));
+    assert_eq!(Step::backward_checked('\u{E000}', 1_usize), Some('\u{D7FF}'));
+    assert_eq!(Step::backward_checked('\u{E001}', 3_usize), Some('\u{D7FE}'));
+    assert_eq!(Step::backward_checked('\0', 1_usize), None);
+
+    assert!(('a'..='z').eq("abcdefghijklmnopqrstuvwxyz".chars()));
+}
@@ -1,7 +1,9 @@
 #![feature(alloc_layout_extra)]
 #![feature(array_chunks)]
+#![feature(array_into_iter_constructors)]
 #![feature(array_methods)]
 #![feature(array_windows)]
+#![feature(ascii_char)]
 #![feature(bigint_helper_methods)]
 #![feature(cell_update)]
 #![feature(const_align_offset)]
@@ -70,13 +72,19 @@
 #![feature(ip_in_core)]
 #![feature(iter_advance_by)]
 #![feature(iter_array_chunks)]
+#![feature(iter_checked_sum)]
+#![feature(iter_collect_array)]
 #![feature(iter_collect_into)]
+#![feature(iter_cycle_n)]
 #![feature(iter_partition_in_place)]
 #![feature(iter_intersperse)]
 #![feature(iter_is_partitioned)]
 #![feature(iter_next_chunk)]
 #![feature(iter_order_by)]
 #![feature(iter_repeat_n)]
+#![feature(iter_rfind_map)]
+#![feature(iter_try_rfor_each)]
+#![feature(iter_zip_all)]
 #![feature(iterator_try_collect)]
 #![feature(iterator_try_reduce)]
 #![feature(const_ip)]
@@ -86,6 +94,14 @@
 #![feature(const_pin)]
 #![feature(const_waker)]
 #![feature(never_type)]
+#![feature(option_result_contains_value)]
+#![feature(option_zip)]
+#![feature(result_contains_err_value)]
+#![feature(result_flattening)]
+#![feature(saturating_int_impl)]
+#![feature(strict_overflow_ops)]
+#![feature(wrapping_int_impl)]
+#![feature(wrapping_next_power_of_two)]
 #![feature(unwrap_infallible)]
 #![feature(pointer_byte_offsets)]
 #![feature(pointer_is_aligned)]
@@ -108,6 +124,7 @@
 #![feature(const_slice_from_ref)]
 #![feature(waker_getters)]
 #![feature(slice_flatten)]
+#![feature(slice_as_chunks)]
 #![feature(provide_any)]
 #![feature(utf8_chunks)]
 #![feature(is_ascii_octdigit)]
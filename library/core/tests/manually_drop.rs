@@ -25,3 +25,35 @@ fn drop(&mut self) {
     drop(x);
     drop(y);
 }
+
+#[test]
+fn take() {
+    use core::cell::Cell;
+
+    struct CountDrops<'a>(&'a Cell<u32>);
+    impl Drop for CountDrops<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    // Hands its field to a callback on drop instead of dropping it in place.
+    struct Guard<'a, F: FnMut(CountDrops<'a>)> {
+        value: ManuallyDrop<CountDrops<'a>>,
+        on_drop: F,
+    }
+    impl<'a, F: FnMut(CountDrops<'a>)> Drop for Guard<'a, F> {
+        fn drop(&mut self) {
+            // SAFETY: `value` is never used again after this.
+            let value = unsafe { ManuallyDrop::take(&mut self.value) };
+            (self.on_drop)(value);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let mut taken = None;
+    drop(Guard { value: ManuallyDrop::new(CountDrops(&drops)), on_drop: |v| taken = Some(v) });
+    assert_eq!(drops.get(), 0);
+    drop(taken);
+    assert_eq!(drops.get(), 1);
+}
@@ -111,6 +111,18 @@ fn test_replace() {
     assert!(y.is_some());
 }
 
+#[test]
+fn test_take() {
+    let mut x = Some("test".to_string());
+    assert_eq!(take(&mut x), Some("test".to_string()));
+    assert_eq!(x, None);
+
+    let mut v = vec![1, 2, 3];
+    assert_eq!(take(&mut v), [1, 2, 3]);
+    assert!(v.is_empty());
+    assert_eq!(take(&mut v), []);
+}
+
 #[test]
 fn test_transmute_copy() {
     assert_eq!(1, unsafe { transmute_copy(&1) });
@@ -200,6 +212,34 @@ fn uninit_array_assume_init() {
     let [] = unsafe { [MaybeUninit::<!>::uninit(); 0].transpose().assume_init() };
 }
 
+#[test]
+fn uninit_hides_niche() {
+    // An uninitialized `&T` or `bool` may hold any bit pattern, so the niche
+    // of the inner type must not be used for the `None` of an `Option`.
+    assert_eq!(size_of::<Option<&u8>>(), size_of::<&u8>());
+    assert!(size_of::<Option<MaybeUninit<&u8>>>() > size_of::<MaybeUninit<&u8>>());
+    assert!(size_of::<Option<MaybeUninit<bool>>>() > size_of::<MaybeUninit<bool>>());
+    assert_eq!(size_of::<MaybeUninit<&u8>>(), size_of::<&u8>());
+    assert_eq!(align_of::<MaybeUninit<u64>>(), align_of::<u64>());
+}
+
+#[test]
+fn uninit_array_of_drop_types() {
+    let mut array = MaybeUninit::<[String; 3]>::uninit().transpose();
+    for (i, slot) in array.iter_mut().enumerate() {
+        slot.write(i.to_string());
+    }
+    let mut array = array.transpose();
+
+    // `assume_init_ref` and `assume_init_read` don't move out of the buffer...
+    assert_eq!(unsafe { array.assume_init_ref() }[1], "1");
+    let copy = unsafe { array.assume_init_read() };
+    assert_eq!(copy, ["0", "1", "2"]);
+    // ...so exactly one of the two copies may be dropped.
+    forget(copy);
+    unsafe { array.assume_init_drop() };
+}
+
 #[test]
 fn uninit_write_slice() {
     let mut dst = [MaybeUninit::new(255); 64];
@@ -804,6 +804,30 @@ fn test_int_to_ipv6() {
     assert_eq!(Ipv6Addr::from(0x112233445566778899aabbccddeeff11u128), a);
 }
 
+#[test]
+fn ip_to_canonical() {
+    let mapped = Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc0a8, 0x0101);
+    assert_eq!(mapped.to_canonical(), IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)));
+    assert_eq!(IpAddr::V6(mapped).to_canonical(), IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)));
+
+    // Only IPv4-mapped addresses are converted, not the deprecated IPv4-compatible ones.
+    let compatible = Ipv4Addr::new(192, 168, 1, 1).to_ipv6_compatible();
+    assert_eq!(compatible.to_canonical(), IpAddr::V6(compatible));
+    assert_eq!(Ipv6Addr::LOCALHOST.to_canonical(), IpAddr::V6(Ipv6Addr::LOCALHOST));
+    assert_eq!(Ipv6Addr::UNSPECIFIED.to_canonical(), IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+
+    let v4 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+    assert_eq!(v4.to_canonical(), v4);
+
+    // Classification of a mapped address follows its IPv4 meaning once canonicalized.
+    let mapped_loopback = IpAddr::V6(Ipv4Addr::LOCALHOST.to_ipv6_mapped());
+    assert!(!mapped_loopback.is_loopback());
+    assert!(mapped_loopback.to_canonical().is_loopback());
+
+    const CANONICAL: IpAddr = IpAddr::V6(Ipv4Addr::BROADCAST.to_ipv6_mapped()).to_canonical();
+    assert_eq!(CANONICAL, IpAddr::V4(Ipv4Addr::BROADCAST));
+}
+
 #[test]
 fn ipv4_from_constructors() {
     assert_eq!(Ipv4Addr::LOCALHOST, Ipv4Addr::new(127, 0, 0, 1));
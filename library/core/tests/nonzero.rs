@@ -334,3 +334,42 @@ fn test_nonzero_uint_rem() {
     let x: u32 = 42u32 % nz;
     assert_eq!(x, 2u32);
 }
+
+#[test]
+fn nonzero_checked_ops() {
+    let one = NonZeroU8::new(1).unwrap();
+    let two = NonZeroU8::new(2).unwrap();
+    let max = NonZeroU8::new(u8::MAX).unwrap();
+    assert_eq!(one.checked_add(1), Some(two));
+    assert_eq!(max.checked_add(1), None);
+    assert_eq!(max.saturating_add(1), max);
+    assert_eq!(two.checked_mul(two), NonZeroU8::new(4));
+    assert_eq!(max.checked_mul(two), None);
+    assert_eq!(two.checked_pow(7), NonZeroU8::new(128));
+    assert_eq!(two.checked_pow(8), None);
+    assert_eq!(two.saturating_pow(8), max);
+
+    let neg_two = NonZeroI32::new(-2).unwrap();
+    let min = NonZeroI32::new(i32::MIN).unwrap();
+    assert_eq!(neg_two.checked_mul(neg_two), NonZeroI32::new(4));
+    assert_eq!(min.checked_mul(neg_two), None);
+    assert_eq!(neg_two.checked_pow(3), NonZeroI32::new(-8));
+    assert_eq!(min.checked_pow(2), None);
+    assert_eq!(neg_two.checked_abs(), NonZeroI32::new(2));
+    assert_eq!(min.checked_abs(), None);
+    assert_eq!(min.unsigned_abs(), NonZeroU32::new(1 << 31).unwrap());
+}
+
+#[test]
+fn nonzero_widening_from() {
+    let x = NonZeroU8::new(200).unwrap();
+    assert_eq!(NonZeroU16::from(x).get(), 200);
+    assert_eq!(NonZeroU64::from(x).get(), 200);
+    assert_eq!(NonZeroI16::from(x).get(), 200);
+    assert_eq!(u8::from(x), 200);
+
+    let y = NonZeroI8::new(-100).unwrap();
+    assert_eq!(NonZeroI32::from(y).get(), -100);
+    assert_eq!(NonZeroI128::from(y).get(), -100);
+    assert_eq!(NonZeroIsize::from(y).get(), -100);
+}
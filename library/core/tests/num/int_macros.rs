@@ -22,6 +22,36 @@ fn test_num() {
             #[test]
             fn test_rem_euclid() {
                 assert_eq!((-1 as $T).rem_euclid(MIN), MAX);
+                assert_eq!((7 as $T).rem_euclid(4), 3);
+                assert_eq!((-7 as $T).rem_euclid(4), 1);
+                assert_eq!((7 as $T).rem_euclid(-4), 3);
+                assert_eq!((-7 as $T).rem_euclid(-4), 1);
+
+                assert_eq!((-7 as $T).checked_rem_euclid(4), Some(1));
+                assert_eq!((7 as $T).checked_rem_euclid(0), None);
+                assert_eq!(MIN.checked_rem_euclid(-1), None);
+                assert_eq!(MIN.wrapping_rem_euclid(-1), 0);
+                assert_eq!(MIN.overflowing_rem_euclid(-1), (0, true));
+            }
+
+            #[test]
+            fn test_div_euclid() {
+                assert_eq!((7 as $T).div_euclid(4), 1);
+                assert_eq!((-7 as $T).div_euclid(4), -2);
+                assert_eq!((7 as $T).div_euclid(-4), -1);
+                assert_eq!((-7 as $T).div_euclid(-4), 2);
+                let cases = [(7 as $T, 4 as $T), (-7, 4), (7, -4), (-7, -4), (MIN, 3), (MAX, -3)];
+                for (a, b) in cases {
+                    assert_eq!(a.div_euclid(b).wrapping_mul(b).wrapping_add(a.rem_euclid(b)), a);
+                    assert!(a.rem_euclid(b) >= 0);
+                }
+
+                assert_eq!((-7 as $T).checked_div_euclid(4), Some(-2));
+                assert_eq!((7 as $T).checked_div_euclid(0), None);
+                assert_eq!(MIN.checked_div_euclid(-1), None);
+                assert_eq!(MIN.wrapping_div_euclid(-1), MIN);
+                assert_eq!(MIN.overflowing_div_euclid(-1), (MIN, true));
+                assert_eq!((-7 as $T).overflowing_div_euclid(4), (-2, false));
             }
 
             #[test]
@@ -364,6 +394,105 @@ fn test_borrowing_sub() {
                 assert_eq!((0 as $T).borrowing_sub($T::MIN, false), ($T::MIN, true));
                 assert_eq!((0 as $T).borrowing_sub($T::MIN, true), ($T::MAX, false));
             }
+
+            #[test]
+            fn test_to_from_bytes() {
+                let x = 0x0123_4567_89ab_cdef_1122_3344_5566_7788_u128 as $T;
+                let be = x.to_be_bytes();
+                let le = x.to_le_bytes();
+                let n = core::mem::size_of::<$T>();
+                assert_eq!(be.len(), n);
+                assert_eq!(be[n - 1], 0x88);
+                assert_eq!(le[0], 0x88);
+                for i in 0..n {
+                    assert_eq!(be[i], le[n - 1 - i]);
+                }
+
+                assert_eq!($T::from_be_bytes(be), x);
+                assert_eq!($T::from_le_bytes(le), x);
+                assert_eq!($T::from_ne_bytes(x.to_ne_bytes()), x);
+                if cfg!(target_endian = "little") {
+                    assert_eq!(x.to_ne_bytes(), le);
+                } else {
+                    assert_eq!(x.to_ne_bytes(), be);
+                }
+
+                let neg = (-2 as $T).to_be_bytes();
+                assert!(neg[..n - 1].iter().all(|&b| b == 0xff));
+                assert_eq!(neg[n - 1], 0xfe);
+                assert_eq!($T::from_be_bytes(neg), -2);
+
+                const BYTES: [u8; core::mem::size_of::<$T>()] = $T::MAX.to_le_bytes();
+                const MAX: $T = $T::from_le_bytes(BYTES);
+                assert_eq!(MAX, $T::MAX);
+            }
+
+            #[test]
+            fn test_strict_ops() {
+                assert_eq!(($T::MAX - 2).strict_add(1), $T::MAX - 1);
+                assert_eq!((1 as $T).strict_add_unsigned(2), 3);
+                assert_eq!(($T::MIN + 2).strict_sub(1), $T::MIN + 1);
+                assert_eq!((1 as $T).strict_sub_unsigned(2), -1);
+                assert_eq!($T::MAX.strict_mul(-1), $T::MIN + 1);
+                assert_eq!(($T::MIN + 1).strict_div(-1), $T::MAX);
+                assert_eq!((-7 as $T).strict_div_euclid(2), -4);
+                assert_eq!((-7 as $T).strict_rem(2), -1);
+                assert_eq!((-7 as $T).strict_rem_euclid(2), 1);
+                assert_eq!($T::MAX.strict_neg(), $T::MIN + 1);
+                assert_eq!((1 as $T).strict_shl($T::BITS - 1), $T::MIN);
+                assert_eq!($T::MIN.strict_shr($T::BITS - 1), -1);
+                assert_eq!(($T::MIN + 1).strict_abs(), $T::MAX);
+                assert_eq!((-2 as $T).strict_pow($T::BITS - 1), $T::MIN);
+                assert_eq!((3 as $T).strict_pow(0), 1);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to add with overflow")]
+            fn test_strict_add_overflow() {
+                let _ = $T::MAX.strict_add(1);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to subtract with overflow")]
+            fn test_strict_sub_unsigned_overflow() {
+                let _ = $T::MIN.strict_sub_unsigned(1);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to multiply with overflow")]
+            fn test_strict_mul_overflow() {
+                let _ = $T::MIN.strict_mul(-1);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to divide with overflow")]
+            fn test_strict_div_overflow() {
+                let _ = $T::MIN.strict_div(-1);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to calculate the remainder with overflow")]
+            fn test_strict_rem_euclid_overflow() {
+                let _ = $T::MIN.strict_rem_euclid(-1);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to negate with overflow")]
+            fn test_strict_abs_overflow() {
+                let _ = $T::MIN.strict_abs();
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to shift left with overflow")]
+            fn test_strict_shl_overflow() {
+                let _ = (1 as $T).strict_shl($T::BITS);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to multiply with overflow")]
+            fn test_strict_pow_overflow() {
+                let _ = (2 as $T).strict_pow($T::BITS - 1);
+            }
         }
     };
 }
@@ -869,3 +869,49 @@ fn div_euclid() {
 
 test_float!(f32, f32, f32::INFINITY, f32::NEG_INFINITY, f32::NAN);
 test_float!(f64, f64, f64::INFINITY, f64::NEG_INFINITY, f64::NAN);
+
+#[test]
+fn test_bigint_helpers_multi_limb() {
+    // Schoolbook arithmetic on little-endian `u32` limbs, checked against `u128`.
+    fn to_limbs(x: u128) -> [u32; 4] {
+        [x as u32, (x >> 32) as u32, (x >> 64) as u32, (x >> 96) as u32]
+    }
+    fn from_limbs(l: [u32; 4]) -> u128 {
+        l.iter().rev().fold(0, |acc, &limb| (acc << 32) | limb as u128)
+    }
+
+    let values = [
+        0,
+        1,
+        u32::MAX as u128,
+        u64::MAX as u128,
+        0x1234_5678_9abc_def0_0fed_cba9,
+        u128::MAX,
+    ];
+    for &a in &values {
+        for &b in &values {
+            let (x, y) = (to_limbs(a), to_limbs(b));
+
+            let mut sum = [0; 4];
+            let mut carry = false;
+            for i in 0..4 {
+                (sum[i], carry) = x[i].carrying_add(y[i], carry);
+            }
+            assert_eq!((from_limbs(sum), carry), a.overflowing_add(b));
+
+            let mut diff = [0; 4];
+            let mut borrow = false;
+            for i in 0..4 {
+                (diff[i], borrow) = x[i].borrowing_sub(y[i], borrow);
+            }
+            assert_eq!((from_limbs(diff), borrow), a.overflowing_sub(b));
+
+            let mut prod = [0; 4];
+            let mut carry = 0;
+            for i in 0..4 {
+                (prod[i], carry) = x[i].carrying_mul(y[0], carry);
+            }
+            assert_eq!(from_limbs(prod), a.wrapping_mul(y[0] as u128));
+        }
+    }
+}
@@ -1,5 +1,28 @@
 macro_rules! uint_module {
+    // `widening_mul` and `carrying_mul` have no `u128` implementation yet.
+    (u128) => {
+        uint_module!(u128, {});
+    };
     ($T:ident) => {
+        uint_module!($T, {
+            #[test]
+            fn test_widening_mul() {
+                assert_eq!((0 as $T).widening_mul($T::MAX), (0, 0));
+                assert_eq!((5 as $T).widening_mul(2), (10, 0));
+                assert_eq!($T::MAX.widening_mul(2), ($T::MAX - 1, 1));
+                assert_eq!($T::MAX.widening_mul($T::MAX), (1, $T::MAX - 1));
+            }
+
+            #[test]
+            fn test_carrying_mul() {
+                assert_eq!((5 as $T).carrying_mul(2, 3), (13, 0));
+                assert_eq!($T::MAX.carrying_mul(1, 1), (0, 1));
+                // The full result always fits: MAX * MAX + MAX == MAX << BITS.
+                assert_eq!($T::MAX.carrying_mul($T::MAX, $T::MAX), (0, $T::MAX));
+            }
+        });
+    };
+    ($T:ident, { $($extra_tests:tt)* }) => {
         #[cfg(test)]
         mod tests {
             use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
@@ -252,6 +275,83 @@ fn test_borrowing_sub() {
                 assert_eq!($T::MAX.borrowing_sub(0, true), ($T::MAX - 1, false));
                 assert_eq!($T::MAX.borrowing_sub($T::MAX, true), ($T::MAX, true));
             }
+
+            #[test]
+            fn test_to_from_bytes() {
+                let x = 0x0123_4567_89ab_cdef_1122_3344_5566_7788_u128 as $T;
+                let be = x.to_be_bytes();
+                let le = x.to_le_bytes();
+                let n = core::mem::size_of::<$T>();
+                assert_eq!(be.len(), n);
+                assert_eq!(be[n - 1], 0x88);
+                assert_eq!(le[0], 0x88);
+                for i in 0..n {
+                    assert_eq!(be[i], le[n - 1 - i]);
+                }
+
+                assert_eq!($T::from_be_bytes(be), x);
+                assert_eq!($T::from_le_bytes(le), x);
+                assert_eq!($T::from_ne_bytes(x.to_ne_bytes()), x);
+                if cfg!(target_endian = "little") {
+                    assert_eq!(x.to_ne_bytes(), le);
+                } else {
+                    assert_eq!(x.to_ne_bytes(), be);
+                }
+
+                const BYTES: [u8; core::mem::size_of::<$T>()] = $T::MAX.to_le_bytes();
+                const MAX: $T = $T::from_le_bytes(BYTES);
+                assert_eq!(MAX, $T::MAX);
+            }
+
+            #[test]
+            fn test_strict_ops() {
+                assert_eq!(($T::MAX - 2).strict_add(1), $T::MAX - 1);
+                assert_eq!((3 as $T).strict_add_signed(-2), 1);
+                assert_eq!(($T::MAX - 2).strict_add_signed(2), $T::MAX);
+                assert_eq!((1 as $T).strict_sub(1), 0);
+                assert_eq!(($T::MAX / 2).strict_mul(2), $T::MAX - 1);
+                assert_eq!((7 as $T).strict_div(2), 3);
+                assert_eq!((7 as $T).strict_div_euclid(2), 3);
+                assert_eq!((7 as $T).strict_rem(2), 1);
+                assert_eq!((7 as $T).strict_rem_euclid(2), 1);
+                assert_eq!((0 as $T).strict_neg(), 0);
+                assert_eq!((1 as $T).strict_shl($T::BITS - 1), 1 << ($T::BITS - 1));
+                assert_eq!($T::MAX.strict_shr($T::BITS - 1), 1);
+                assert_eq!((2 as $T).strict_pow($T::BITS - 1), 1 << ($T::BITS - 1));
+                assert_eq!((3 as $T).strict_pow(0), 1);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to add with overflow")]
+            fn test_strict_add_signed_overflow() {
+                let _ = (1 as $T).strict_add_signed(-2);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to subtract with overflow")]
+            fn test_strict_sub_overflow() {
+                let _ = (0 as $T).strict_sub(1);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to negate with overflow")]
+            fn test_strict_neg_overflow() {
+                let _ = (1 as $T).strict_neg();
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to shift right with overflow")]
+            fn test_strict_shr_overflow() {
+                let _ = (1 as $T).strict_shr($T::BITS);
+            }
+
+            #[test]
+            #[should_panic(expected = "attempt to multiply with overflow")]
+            fn test_strict_pow_overflow() {
+                let _ = (2 as $T).strict_pow($T::BITS);
+            }
+
+            $($extra_tests)*
         }
     };
 }
@@ -316,3 +316,38 @@ fn wrapping_const() {
         assert!(i32::MIN.wrapping_rem(-1) == 0);
     };
 }
+
+#[test]
+fn wrapping_inherent_api() {
+    assert_eq!(Wrapping::<u8>::MIN, Wrapping(0));
+    assert_eq!(Wrapping::<u8>::MAX, Wrapping(u8::MAX));
+    assert_eq!(Wrapping::<u8>::BITS, 8);
+
+    assert_eq!(Wrapping(3u8).pow(5), Wrapping(243));
+    assert_eq!(Wrapping(3u8).pow(6), Wrapping(217));
+    assert_eq!(Wrapping(3i8).pow(5), Wrapping(-13));
+
+    let x = Wrapping(0b0000_0110_u8);
+    assert_eq!(x.count_ones(), 2);
+    assert_eq!(x.count_zeros(), 6);
+    assert_eq!(x.leading_zeros(), 5);
+    assert_eq!(x.trailing_zeros(), 1);
+    assert_eq!(x.rotate_left(7), Wrapping(0b0000_0011));
+    assert_eq!(x.rotate_right(2), Wrapping(0b1000_0001));
+    assert_eq!(x.reverse_bits(), Wrapping(0b0110_0000));
+    assert_eq!(Wrapping(0x1234u16).swap_bytes(), Wrapping(0x3412));
+    assert_eq!(Wrapping::<u16>::from_be(Wrapping(0x1234u16).to_be()), Wrapping(0x1234));
+    assert_eq!(Wrapping::<u16>::from_le(Wrapping(0x1234u16).to_le()), Wrapping(0x1234));
+
+    assert!(!x.is_power_of_two());
+    assert_eq!(x.next_power_of_two(), Wrapping(8));
+    assert_eq!(Wrapping(200u8).next_power_of_two(), Wrapping(0));
+
+    assert_eq!(Wrapping(-5i32).abs(), Wrapping(5));
+    assert_eq!(Wrapping(i32::MIN).abs(), Wrapping(i32::MIN));
+    assert_eq!(Wrapping(-5i32).signum(), Wrapping(-1));
+    assert_eq!(Wrapping(0i32).signum(), Wrapping(0));
+    assert!(Wrapping(-5i32).is_negative());
+    assert!(Wrapping(5i32).is_positive());
+    assert_eq!(Wrapping(-1i16).leading_zeros(), 0);
+}
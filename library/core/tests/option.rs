@@ -155,6 +155,29 @@ fn test_or() {
     assert_eq!(D, None);
 }
 
+#[test]
+fn test_xor() {
+    let x: Option<isize> = Some(1);
+    assert_eq!(x.xor(Some(2)), None);
+    assert_eq!(x.xor(None), Some(1));
+
+    let x: Option<isize> = None;
+    assert_eq!(x.xor(Some(2)), Some(2));
+    assert_eq!(x.xor(None), None);
+
+    const FOO: Option<isize> = Some(1);
+    const A: Option<isize> = FOO.xor(Some(2));
+    const B: Option<isize> = FOO.xor(None);
+    assert_eq!(A, None);
+    assert_eq!(B, Some(1));
+
+    const BAR: Option<isize> = None;
+    const C: Option<isize> = BAR.xor(Some(2));
+    const D: Option<isize> = BAR.xor(None);
+    assert_eq!(C, Some(2));
+    assert_eq!(D, None);
+}
+
 #[test]
 fn test_or_else() {
     const fn two() -> Option<isize> {
@@ -432,6 +455,36 @@ fn test_replace() {
     assert_eq!(old, None);
 }
 
+#[test]
+fn test_insert() {
+    let mut x = None;
+    let val = x.insert(1);
+    assert_eq!(*val, 1);
+    *val += 1;
+    assert_eq!(x, Some(2));
+
+    // Unlike `get_or_insert`, an existing value is overwritten.
+    let val = x.insert(5);
+    assert_eq!(*val, 5);
+    assert_eq!(x, Some(5));
+    assert_eq!(*x.get_or_insert(7), 5);
+
+    let dropped = Cell::new(0);
+    struct Dtor<'a>(&'a Cell<i32>, i32);
+    impl Drop for Dtor<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + self.1);
+        }
+    }
+
+    let mut x = Some(Dtor(&dropped, 1));
+    let val = x.insert(Dtor(&dropped, 10));
+    assert_eq!(dropped.get(), 1);
+    val.1 = 100;
+    drop(x);
+    assert_eq!(dropped.get(), 101);
+}
+
 #[test]
 fn option_const() {
     // test that the methods of `Option` are usable in a const context
@@ -523,6 +576,35 @@ fn option_ext() {
     }
 }
 
+#[test]
+fn test_contains() {
+    assert!(Some(2).contains(&2));
+    assert!(!Some(3).contains(&2));
+    assert!(!None::<u32>.contains(&2));
+
+    let x: Option<&str> = Some("hello");
+    assert!(x.contains(&"hello"));
+    assert!(!x.contains(&"world"));
+
+    let x: Option<String> = Some(String::from("hello"));
+    assert!(x.contains("hello"));
+    assert!(!x.contains("world"));
+}
+
+#[test]
+fn test_flatten() {
+    assert_eq!(Some(Some(6)).flatten(), Some(6));
+    assert_eq!(Some(None::<u32>).flatten(), None);
+    assert_eq!(None::<Option<u32>>.flatten(), None);
+
+    let x = Some(Some(Some(6)));
+    assert_eq!(x.flatten(), Some(Some(6)));
+    assert_eq!(x.flatten().flatten(), Some(6));
+
+    const FLAT: Option<u32> = Some(Some(32)).flatten();
+    assert_eq!(FLAT, Some(32));
+}
+
 #[test]
 fn zip_options() {
     let x = Some(10);
@@ -534,6 +616,19 @@ fn zip_options() {
     assert_eq!(z.zip(x), None);
 }
 
+#[test]
+fn zip_with_options() {
+    let x = Some(10);
+    let y = Some(3);
+    let z: Option<i32> = None;
+
+    assert_eq!(x.zip_with(y, |a, b| a - b), Some(7));
+    assert_eq!(y.zip_with(x, |a, b| a - b), Some(-7));
+    assert_eq!(x.zip_with(z, |_, _| -> i32 { panic!("called with a `None`") }), None);
+    assert_eq!(z.zip_with(x, |_, _| -> i32 { panic!("called with a `None`") }), None);
+    assert_eq!(x.zip_with(Some("ab"), |n, s| s.repeat(n as usize).len()), Some(20));
+}
+
 #[test]
 fn unzip_options() {
     let x = Some((10, "foo"));
@@ -1,4 +1,8 @@
+use core::future::Future;
+use core::marker::PhantomPinned;
 use core::pin::Pin;
+use core::ptr;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 
 #[test]
 fn pin_const() {
@@ -29,3 +33,81 @@ const fn pin_mut_const() {
 
     pin_mut_const();
 }
+
+#[test]
+fn pin_projection() {
+    // A future that yields once, wrapping another future it may not move out of.
+    struct YieldThen<F> {
+        yielded: bool,
+        inner: F,
+    }
+
+    impl<F: Future> Future for YieldThen<F> {
+        type Output = F::Output;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+            // SAFETY: `inner` is structurally pinned and `yielded` is not.
+            let this = unsafe { self.get_unchecked_mut() };
+            if !this.yielded {
+                this.yielded = true;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            unsafe { Pin::new_unchecked(&mut this.inner) }.poll(cx)
+        }
+    }
+
+    // Only hands out its address once it is pinned, so it must never move.
+    struct SelfAddr {
+        addr: *const SelfAddr,
+        _pinned: PhantomPinned,
+    }
+
+    impl Future for SelfAddr {
+        type Output = *const SelfAddr;
+
+        fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<*const SelfAddr> {
+            let addr = &*self as *const SelfAddr;
+            // SAFETY: only the unpinned `addr` field is touched.
+            let this = unsafe { self.get_unchecked_mut() };
+            if this.addr.is_null() {
+                this.addr = addr;
+            }
+            assert_eq!(this.addr, addr);
+            Poll::Ready(addr)
+        }
+    }
+
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(ptr::null(), &VTABLE),
+        |_| {},
+        |_| {},
+        |_| {},
+    );
+    let waker = unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) };
+    let mut cx = Context::from_waker(&waker);
+
+    let mut fut = YieldThen {
+        yielded: false,
+        inner: SelfAddr { addr: ptr::null(), _pinned: PhantomPinned },
+    };
+    // SAFETY: `fut` is shadowed, so it can't be moved after being pinned.
+    let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+    assert_eq!(fut.as_mut().poll(&mut cx), Poll::Pending);
+    let addr = match fut.as_mut().poll(&mut cx) {
+        Poll::Ready(addr) => addr,
+        Poll::Pending => panic!("should be ready after yielding once"),
+    };
+    let inner = unsafe { fut.as_mut().map_unchecked_mut(|f| &mut f.inner) };
+    assert_eq!(addr, &*inner as *const SelfAddr);
+}
+
+#[test]
+fn pin_set_and_unpin() {
+    let mut value = 1;
+    let mut pinned = Pin::new(&mut value);
+    pinned.set(2);
+    *pinned.as_mut().get_mut() += 1;
+    assert_eq!(*pinned, 3);
+    assert_eq!(Pin::into_inner(pinned), &mut 3);
+}
@@ -366,6 +366,38 @@ const fn result_const_mut() {
     }
 }
 
+#[test]
+fn test_contains() {
+    let x: Result<u32, &str> = Ok(2);
+    assert!(x.contains(&2));
+    assert!(!x.contains(&3));
+    assert!(!x.contains_err(&"error"));
+
+    let x: Result<u32, &str> = Err("error");
+    assert!(!x.contains(&2));
+    assert!(x.contains_err(&"error"));
+    assert!(!x.contains_err(&"other"));
+
+    let x: Result<String, String> = Ok(String::from("ok"));
+    assert!(x.contains("ok"));
+    assert!(!x.contains_err("ok"));
+}
+
+#[test]
+fn test_flatten() {
+    let x: Result<Result<u32, &str>, &str> = Ok(Ok(6));
+    assert_eq!(x.flatten(), Ok(6));
+
+    let x: Result<Result<u32, &str>, &str> = Ok(Err("inner"));
+    assert_eq!(x.flatten(), Err("inner"));
+
+    let x: Result<Result<u32, &str>, &str> = Err("outer");
+    assert_eq!(x.flatten(), Err("outer"));
+
+    const FLAT: Result<u32, bool> = Ok::<_, bool>(Ok(32)).flatten();
+    assert_eq!(FLAT, Ok(32));
+}
+
 #[test]
 fn result_opt_conversions() {
     #[derive(Copy, Clone, Debug, PartialEq)]
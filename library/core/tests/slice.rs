@@ -137,6 +137,23 @@ fn test_partition_point() {
     assert_eq!(b.partition_point(|&x| x < 8), 5);
 }
 
+#[test]
+fn test_partition_point_matches_linear_scan() {
+    // Miri is too slow
+    let max_len = if cfg!(miri) { 8 } else { 64 };
+    for len in 0..max_len {
+        let v: Vec<usize> = (0..len).map(|i| i / 3).collect();
+        for bound in 0..=len / 3 + 1 {
+            let expected = v.iter().position(|&x| x >= bound).unwrap_or(len);
+            assert_eq!(v.partition_point(|&x| x < bound), expected);
+        }
+    }
+
+    // The predicate need not come from an `Ord` implementation.
+    let floats = [-1.5, -0.0, 0.0, 2.5, f64::INFINITY];
+    assert_eq!(floats.partition_point(|x| x.is_sign_negative()), 2);
+}
+
 #[test]
 fn test_iterator_advance_by() {
     let v = &[0, 1, 2, 3, 4];
@@ -181,6 +198,33 @@ fn test_iterator_advance_back_by() {
     assert_eq!(iter.advance_back_by(0), Ok(()));
 }
 
+#[test]
+fn test_iterator_next_chunk() {
+    let v = &[0, 1, 2, 3, 4];
+
+    let mut iter = v.iter();
+    assert_eq!(iter.next_chunk().unwrap(), [&0, &1]);
+    assert_eq!(iter.next_chunk::<0>().unwrap(), [] as [&i32; 0]);
+    assert_eq!(iter.as_slice(), &[2, 3, 4]);
+    assert_eq!(iter.next_chunk::<4>().unwrap_err().as_slice(), &[&2, &3, &4]);
+    assert_eq!(iter.as_slice(), &[]);
+
+    let mut v = [0, 1, 2, 3, 4];
+    let mut iter = v.iter_mut();
+    let [a, b, c] = iter.next_chunk().unwrap();
+    *a += 10;
+    *b += 10;
+    *c += 10;
+    assert_eq!(iter.into_slice(), &[3, 4]);
+    assert_eq!(v, [10, 11, 12, 3, 4]);
+
+    let z = &[(); 3];
+    let mut iter = z.iter();
+    assert_eq!(iter.next_chunk().unwrap(), [&(), &()]);
+    assert_eq!(iter.next_chunk::<2>().unwrap_err().as_slice(), &[&()]);
+    assert_eq!(iter.len(), 0);
+}
+
 #[test]
 fn test_iterator_nth() {
     let v: &[_] = &[0, 1, 2, 3, 4];
@@ -611,6 +655,49 @@ fn test_chunks_exact_mut_zip() {
     assert_eq!(v1, [13, 14, 19, 20, 4]);
 }
 
+#[test]
+fn test_as_chunks() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5, 6];
+    let (chunks, remainder) = v.as_chunks::<3>();
+    assert_eq!(chunks, &[[0, 1, 2], [3, 4, 5]]);
+    assert_eq!(remainder, &[6]);
+
+    let (remainder, chunks) = v.as_rchunks::<3>();
+    assert_eq!(remainder, &[0]);
+    assert_eq!(chunks, &[[1, 2, 3], [4, 5, 6]]);
+
+    let (chunks, remainder) = v.as_chunks::<8>();
+    assert!(chunks.is_empty());
+    assert_eq!(remainder, v);
+
+    let (chunks, remainder) = v.as_chunks::<1>();
+    assert_eq!(chunks.len(), v.len());
+    assert!(remainder.is_empty());
+}
+
+#[test]
+fn test_as_chunks_mut() {
+    let v: &mut [i32] = &mut [0, 1, 2, 3, 4, 5, 6];
+    let (chunks, remainder) = v.as_chunks_mut::<2>();
+    for [a, b] in chunks {
+        core::mem::swap(a, b);
+    }
+    remainder[0] = -1;
+    assert_eq!(v, &[1, 0, 3, 2, 5, 4, -1]);
+
+    let (remainder, chunks) = v.as_rchunks_mut::<3>();
+    chunks[0] = [7, 8, 9];
+    remainder[0] = 10;
+    assert_eq!(v, &[10, 7, 8, 9, 5, 4, -1]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_as_chunks_zero() {
+    let v: &[i32] = &[0, 1, 2];
+    let _ = v.as_chunks::<0>();
+}
+
 #[test]
 fn test_array_chunks_infer() {
     let v: &[i32] = &[0, 1, 2, 3, 4, -4];
@@ -1980,6 +2067,24 @@ fn select_nth_unstable() {
     assert!(v == [0xDEADBEEF]);
 }
 
+#[test]
+fn select_nth_unstable_by_key() {
+    // Median and 90th percentile by absolute value, without a full sort.
+    let mut v = [-9, 4, -1, 7, 0, -3, 8, 2, -6, 5];
+    let (left, median, right) = v.select_nth_unstable_by_key(5, |x: &i32| x.abs());
+    assert_eq!(*median, 5);
+    assert!(left.iter().all(|x| x.abs() <= 5));
+    assert!(right.iter().all(|x| x.abs() >= 5));
+
+    let (_, p90, right) = v.select_nth_unstable_by_key(9, |x: &i32| x.abs());
+    assert_eq!(*p90, -9);
+    assert!(right.is_empty());
+
+    let mut words = ["cherry", "fig", "banana", "kiwi", "apple"];
+    let (_, shortest, _) = words.select_nth_unstable_by_key(0, |w| w.len());
+    assert_eq!(*shortest, "fig");
+}
+
 #[test]
 #[should_panic(expected = "index 0 greater than length of slice")]
 fn select_nth_unstable_zero_length() {
@@ -2324,6 +2429,50 @@ fn test_const_from_ref() {
     assert!(core::ptr::eq(VALUE, &SLICE[0]))
 }
 
+#[test]
+fn test_slice_fill() {
+    let mut bytes = [0u8; 7];
+    bytes.fill(0xAB);
+    assert_eq!(bytes, [0xAB; 7]);
+
+    let mut strings = vec![String::new(); 3];
+    strings[1..].fill(String::from("x"));
+    assert_eq!(strings, ["", "x", "x"]);
+
+    // The value is cloned into all but the last slot, which takes it by move.
+    let clones = Cell::new(0);
+    #[derive(Debug)]
+    struct Counted<'a>(&'a Cell<usize>);
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            Counted(self.0)
+        }
+    }
+    let mut v = [Counted(&clones), Counted(&clones), Counted(&clones), Counted(&clones)];
+    v.fill(Counted(&clones));
+    assert_eq!(clones.get(), 3);
+
+    let mut empty: [Counted<'_>; 0] = [];
+    empty.fill(Counted(&clones));
+    assert_eq!(clones.get(), 3);
+}
+
+#[test]
+fn test_slice_fill_with() {
+    let mut v = [0; 5];
+    let mut next = 0;
+    v.fill_with(|| {
+        next += 2;
+        next
+    });
+    assert_eq!(v, [2, 4, 6, 8, 10]);
+
+    let mut strings = [String::from("a"), String::from("b")];
+    strings.fill_with(Default::default);
+    assert_eq!(strings, ["", ""]);
+}
+
 #[test]
 fn test_slice_fill_with_uninit() {
     // This should not UB. See #87891
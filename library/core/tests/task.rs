@@ -1,4 +1,5 @@
-use core::task::{Poll, RawWaker, RawWakerVTable, Waker};
+use core::cell::Cell;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 
 #[test]
 fn poll_const() {
@@ -23,3 +24,61 @@ fn waker_const() {
 
     WAKER.wake_by_ref();
 }
+
+#[test]
+fn waker_vtable_calls() {
+    // Per-task state an executor might point `RawWaker::data` at.
+    #[derive(Default)]
+    struct Calls {
+        clone: Cell<u32>,
+        wake: Cell<u32>,
+        wake_by_ref: Cell<u32>,
+        drop: Cell<u32>,
+    }
+
+    fn bump(cell: &Cell<u32>) {
+        cell.set(cell.get() + 1);
+    }
+
+    unsafe fn calls<'a>(data: *const ()) -> &'a Calls {
+        unsafe { &*data.cast::<Calls>() }
+    }
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |data| {
+            bump(unsafe { &calls(data).clone });
+            RawWaker::new(data, &VTABLE)
+        },
+        |data| bump(unsafe { &calls(data).wake }),
+        |data| bump(unsafe { &calls(data).wake_by_ref }),
+        |data| bump(unsafe { &calls(data).drop }),
+    );
+
+    let state = Calls::default();
+    let other_state = Calls::default();
+    let raw = |calls: &Calls| RawWaker::new(calls as *const Calls as *const (), &VTABLE);
+    let counts = || {
+        (state.clone.get(), state.wake.get(), state.wake_by_ref.get(), state.drop.get())
+    };
+
+    let waker = unsafe { Waker::from_raw(raw(&state)) };
+    let cx = Context::from_waker(&waker);
+    assert!(cx.waker().will_wake(&waker));
+    assert_eq!(cx.waker().as_raw().data(), &state as *const Calls as *const ());
+
+    cx.waker().wake_by_ref();
+    assert_eq!(counts(), (0, 0, 1, 0));
+
+    let cloned = cx.waker().clone();
+    assert_eq!(counts(), (1, 0, 1, 0));
+    assert!(cloned.will_wake(&waker));
+    let other = unsafe { Waker::from_raw(raw(&other_state)) };
+    assert!(!cloned.will_wake(&other));
+
+    // `wake` consumes the waker without also dropping it.
+    cloned.wake();
+    assert_eq!(counts(), (1, 1, 1, 0));
+
+    drop(waker);
+    assert_eq!(counts(), (1, 1, 1, 1));
+}
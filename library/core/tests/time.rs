@@ -220,6 +220,26 @@ fn correct_sum() {
     assert_eq!(sum, Duration::new(1 + 2 + 5 + 4, 1_000_000_000 - 5));
 }
 
+#[test]
+fn sum_owned_and_empty() {
+    let durations = [Duration::new(1, 500_000_000), Duration::new(0, 600_000_000)];
+    assert_eq!(durations.into_iter().sum::<Duration>(), Duration::new(2, 100_000_000));
+    assert_eq!([].iter().sum::<Duration>(), Duration::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn sum_overflow_secs() {
+    let _ = [Duration::MAX, Duration::new(1, 0)].iter().sum::<Duration>();
+}
+
+#[test]
+#[should_panic]
+fn sum_overflow_nanos() {
+    // Only the carry from the nanosecond part overflows, as with `Add`.
+    let _ = [Duration::new(u64::MAX, 999_999_999), Duration::new(0, 1)].iter().sum::<Duration>();
+}
+
 #[test]
 fn debug_formatting_extreme_values() {
     assert_eq!(
@@ -501,3 +521,70 @@ fn from_neg_zero() {
     assert_eq!(Duration::from_secs_f32(-0.0), Duration::ZERO);
     assert_eq!(Duration::from_secs_f64(-0.0), Duration::ZERO);
 }
+
+#[test]
+fn secs_float_round_trip() {
+    let dur = Duration::new(2, 700_000_000);
+    assert_eq!(dur.as_secs_f64(), 2.7);
+    assert_eq!(dur.as_secs_f32(), 2.7);
+    assert_eq!(Duration::from_secs_f64(dur.as_secs_f64()), dur);
+    assert_eq!(Duration::from_secs_f64(0.5), Duration::from_millis(500));
+    assert_eq!(Duration::from_secs_f32(0.25), Duration::from_millis(250));
+    assert_eq!(Duration::ZERO.as_secs_f64(), 0.0);
+    assert_eq!(Duration::NANOSECOND.as_secs_f64(), 1e-9);
+
+    for nanos in [0, 1, 999_999_999, 123_456_789] {
+        let dur = Duration::new(1_000, nanos);
+        assert_eq!(Duration::from_secs_f64(dur.as_secs_f64()), dur);
+    }
+}
+
+#[test]
+fn try_from_secs_float_errors() {
+    assert!(Duration::try_from_secs_f64(-1.0).is_err());
+    assert!(Duration::try_from_secs_f64(f64::NAN).is_err());
+    assert!(Duration::try_from_secs_f64(f64::INFINITY).is_err());
+    assert!(Duration::try_from_secs_f64(u64::MAX as f64 * 2.0).is_err());
+    assert!(Duration::try_from_secs_f32(-1.0).is_err());
+    assert!(Duration::try_from_secs_f32(f32::NAN).is_err());
+    assert!(Duration::try_from_secs_f32(f32::INFINITY).is_err());
+    assert!(Duration::try_from_secs_f32(u64::MAX as f32 * 2.0).is_err());
+}
+
+#[test]
+#[should_panic]
+fn from_secs_f64_negative() {
+    let _ = Duration::from_secs_f64(-0.5);
+}
+
+#[test]
+fn mul_div_float() {
+    let dur = Duration::new(2, 700_000_000);
+    assert_eq!(dur.mul_f64(0.0), Duration::ZERO);
+    assert_eq!(dur.mul_f64(1.0), dur);
+    assert_eq!(dur.mul_f64(0.5), Duration::new(1, 350_000_000));
+    assert_eq!(dur.div_f64(2.0), Duration::new(1, 350_000_000));
+    // `f32` can't hold 2.7 exactly, but 1.5 is fine.
+    let dur = Duration::new(1, 500_000_000);
+    assert_eq!(dur.mul_f32(2.0), Duration::from_secs(3));
+    assert_eq!(dur.div_f32(0.5), Duration::from_secs(3));
+
+    // Backing off by a fractional factor on every retry.
+    let mut delay = Duration::from_millis(100);
+    for _ in 0..3 {
+        delay = delay.mul_f64(1.5);
+    }
+    assert_eq!(delay, Duration::new(0, 337_500_000));
+}
+
+#[test]
+#[should_panic]
+fn mul_f64_overflow() {
+    let _ = Duration::MAX.mul_f64(2.0);
+}
+
+#[test]
+#[should_panic]
+fn div_f64_by_zero() {
+    let _ = Duration::SECOND.div_f64(0.0);
+}
@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use std::sync::mpsc::{channel, sync_channel, Receiver, Sender, SyncSender};
+use std::thread;
+use test::{black_box, Bencher};
+
+const MESSAGES: usize = 10_000;
+const PRODUCERS: usize = 4;
+
+trait AnySender<T> {
+    fn send_ok(&self, t: T);
+}
+
+impl<T> AnySender<T> for Sender<T> {
+    fn send_ok(&self, t: T) {
+        self.send(t).unwrap();
+    }
+}
+
+impl<T> AnySender<T> for SyncSender<T> {
+    fn send_ok(&self, t: T) {
+        self.send(t).unwrap();
+    }
+}
+
+fn ping_pong<S: AnySender<usize>>(tx: &S, rx: &Receiver<usize>) {
+    for i in 0..MESSAGES {
+        tx.send_ok(i);
+        black_box(rx.recv().unwrap());
+    }
+}
+
+fn spsc<S: AnySender<usize> + Send + 'static>(tx: S, rx: Receiver<usize>) {
+    let producer = thread::spawn(move || {
+        for i in 0..MESSAGES {
+            tx.send_ok(i);
+        }
+    });
+    for _ in 0..MESSAGES {
+        black_box(rx.recv().unwrap());
+    }
+    producer.join().unwrap();
+}
+
+fn mpsc<S: AnySender<usize> + Clone + Send + 'static>(tx: S, rx: Receiver<usize>) {
+    let producers: Vec<_> = (0..PRODUCERS)
+        .map(|_| {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for i in 0..MESSAGES / PRODUCERS {
+                    tx.send_ok(i);
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+    assert_eq!(rx.iter().map(black_box).count(), MESSAGES / PRODUCERS * PRODUCERS);
+    for p in producers {
+        p.join().unwrap();
+    }
+}
+
+#[bench]
+fn unbounded_ping_pong(b: &mut Bencher) {
+    let (tx, rx) = channel();
+    b.iter(|| ping_pong(&tx, &rx));
+}
+
+#[bench]
+fn bounded_ping_pong(b: &mut Bencher) {
+    let (tx, rx) = sync_channel(1);
+    b.iter(|| ping_pong(&tx, &rx));
+}
+
+#[bench]
+fn unbounded_spsc(b: &mut Bencher) {
+    b.iter(|| {
+        let (tx, rx) = channel();
+        spsc(tx, rx)
+    });
+}
+
+#[bench]
+fn bounded_spsc(b: &mut Bencher) {
+    b.iter(|| {
+        let (tx, rx) = sync_channel(64);
+        spsc(tx, rx)
+    });
+}
+
+#[bench]
+fn rendezvous_spsc(b: &mut Bencher) {
+    b.iter(|| {
+        let (tx, rx) = sync_channel(0);
+        spsc(tx, rx)
+    });
+}
+
+#[bench]
+fn unbounded_mpsc(b: &mut Bencher) {
+    b.iter(|| {
+        let (tx, rx) = channel();
+        mpsc(tx, rx)
+    });
+}
+
+#[bench]
+fn bounded_mpsc(b: &mut Bencher) {
+    b.iter(|| {
+        let (tx, rx) = sync_channel(64);
+        mpsc(tx, rx)
+    });
+}
+
+#[bench]
+fn rendezvous_mpsc(b: &mut Bencher) {
+    b.iter(|| {
+        let (tx, rx) = sync_channel(0);
+        mpsc(tx, rx)
+    });
+}
+
+#[bench]
+fn try_recv_empty(b: &mut Bencher) {
+    let (_tx, rx) = channel::<usize>();
+    b.iter(|| black_box(rx.try_recv()).unwrap_err());
+}
@@ -748,6 +748,34 @@ fn test_extend_ref() {
     assert_eq!(a[&3], "three");
 }
 
+#[test]
+fn test_insert_remove_churn_does_not_grow() {
+    // Removing an entry usually marks its slot EMPTY and gives it straight
+    // back, but a slot in a full probe group becomes a DELETED tombstone
+    // that only counts against `capacity()` until the next rehash. Once the
+    // free slots run out, a table that is at most half full of live entries
+    // is rehashed in place instead of resized, so a map whose size stays well
+    // below its capacity never grows, however many distinct keys pass
+    // through it.
+    let mut m = HashMap::with_capacity(64);
+    for i in 0..16 {
+        m.insert(i, i);
+    }
+    let cap = m.capacity();
+    assert!(cap >= 64);
+
+    // Miri is too slow
+    let rounds = if cfg!(miri) { 200 } else { 10_000 };
+    for i in 16..16 + rounds {
+        m.insert(i, i);
+        assert_eq!(m.remove(&(i - 16)), Some(i - 16));
+        assert_eq!(m.len(), 16);
+    }
+    assert_eq!(m.len(), 16);
+    assert!(m.capacity() <= cap);
+    assert!((rounds..rounds + 16).all(|i| m[&i] == i));
+}
+
 #[test]
 fn test_capacity_not_less_than_len() {
     let mut a = HashMap::new();
@@ -944,6 +972,43 @@ fn test_raw_entry() {
     }
 }
 
+#[test]
+fn test_raw_entry_interner() {
+    use super::RawEntryMut::{Occupied, Vacant};
+    use crate::cell::Cell;
+    use core::hash::{BuildHasher, Hash, Hasher};
+
+    // A string interner that hashes each lookup once, and only allocates an
+    // owned key when it sees a string for the first time.
+    let mut map: HashMap<String, usize> = HashMap::new();
+    let allocations = Cell::new(0);
+    let mut intern = |s: &str| -> usize {
+        let mut hasher = map.hasher().build_hasher();
+        s.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let next_id = map.len();
+        match map.raw_entry_mut().from_hash(hash, |k| k == s) {
+            Occupied(o) => *o.get(),
+            Vacant(v) => {
+                allocations.set(allocations.get() + 1);
+                *v.insert_hashed_nocheck(hash, s.to_owned(), next_id).1
+            }
+        }
+    };
+
+    assert_eq!(intern("foo"), 0);
+    assert_eq!(intern("bar"), 1);
+    assert_eq!(intern("foo"), 0);
+    assert_eq!(intern("baz"), 2);
+    assert_eq!(intern("bar"), 1);
+    assert_eq!(allocations.get(), 3);
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["baz"], 2);
+    assert_eq!(map.raw_entry().from_key("foo"), Some((&"foo".to_owned(), &0)));
+}
+
 mod test_drain_filter {
     use super::*;
 
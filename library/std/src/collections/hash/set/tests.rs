@@ -378,6 +378,42 @@ fn hash<H: hash::Hasher>(&self, h: &mut H) {
     assert_eq!(it.next(), None);
 }
 
+#[test]
+fn test_get_or_insert() {
+    let mut s: HashSet<String> = HashSet::new();
+
+    let foo = s.get_or_insert(String::from("foo")) as *const String;
+    assert_eq!(s.len(), 1);
+    // A hit hands back the stored value and drops the one passed in.
+    assert_eq!(s.get_or_insert(String::from("foo")) as *const String, foo);
+    assert_eq!(s.len(), 1);
+
+    // `get_or_insert_owned` only clones the borrowed key on a miss.
+    assert_eq!(s.get_or_insert_owned("foo") as *const String, foo);
+    assert_eq!(s.len(), 1);
+    let bar = s.get_or_insert_owned("bar") as *const String;
+    assert_eq!(s.len(), 2);
+    assert_ne!(bar, foo);
+
+    // `get_or_insert_with` only runs the closure on a miss.
+    let mut calls = 0;
+    let baz = s.get_or_insert_with("baz", |k| {
+        calls += 1;
+        k.to_owned()
+    }) as *const String;
+    assert_eq!(calls, 1);
+    for key in ["foo", "bar", "baz"] {
+        s.get_or_insert_with(key, |k| {
+            calls += 1;
+            k.to_owned()
+        });
+    }
+    assert_eq!(calls, 1);
+    assert_eq!(s.len(), 3);
+    assert_eq!(s.get("baz").map(|v| v as *const String), Some(baz));
+    assert_eq!(s.get("bar").map(|v| v as *const String), Some(bar));
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = HashSet::new();
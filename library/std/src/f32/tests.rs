@@ -711,6 +711,20 @@ fn test_float_bits_conv() {
     assert_eq!(f32::from_bits(masked_nan2).to_bits(), masked_nan2);
 }
 
+#[test]
+fn test_clamp() {
+    assert_eq!((-3.0f32).clamp(-2.0, 1.0), -2.0);
+    assert_eq!(0.5f32.clamp(-2.0, 1.0), 0.5);
+    assert_eq!(2.0f32.clamp(-2.0, 1.0), 1.0);
+    assert_eq!(f32::INFINITY.clamp(-2.0, 1.0), 1.0);
+    assert_eq!(f32::NEG_INFINITY.clamp(-2.0, 1.0), -2.0);
+    assert_eq!(1.0f32.clamp(1.0, 1.0), 1.0);
+    // A NaN input is passed through rather than snapped to either bound.
+    assert!(f32::NAN.clamp(-2.0, 1.0).is_nan());
+    // The bounds may be signed zeros of either sign.
+    assert_eq!(0.0f32.clamp(-0.0, 0.0), 0.0);
+}
+
 #[test]
 #[should_panic]
 fn test_clamp_min_greater_than_max() {
@@ -8,6 +8,7 @@
 use crate::hash::{Hash, Hasher};
 use crate::ops;
 use crate::rc::Rc;
+use crate::slice;
 use crate::str::FromStr;
 use crate::sync::Arc;
 
@@ -978,6 +979,203 @@ pub fn is_ascii(&self) -> bool {
     pub fn eq_ignore_ascii_case<S: AsRef<OsStr>>(&self, other: S) -> bool {
         self.inner.eq_ignore_ascii_case(&other.as_ref().inner)
     }
+
+    /// Returns `true` if this string starts with `prefix`.
+    ///
+    /// The rest of the string does not need to be valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_str_ops)]
+    /// use std::ffi::OsStr;
+    ///
+    /// assert!(OsStr::new("--verbose").starts_with("--"));
+    /// assert!(!OsStr::new("-v").starts_with("--"));
+    /// ```
+    #[unstable(feature = "os_str_str_ops", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.bytes().starts_with(prefix.as_bytes())
+    }
+
+    /// Returns `true` if this string ends with `suffix`.
+    ///
+    /// The rest of the string does not need to be valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_str_ops)]
+    /// use std::ffi::OsStr;
+    ///
+    /// assert!(OsStr::new("archive.tar.gz").ends_with(".gz"));
+    /// assert!(!OsStr::new("archive.tar").ends_with(".gz"));
+    /// ```
+    #[unstable(feature = "os_str_str_ops", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.bytes().ends_with(suffix.as_bytes())
+    }
+
+    /// Returns the rest of the string after `prefix`, or `None` if it doesn't
+    /// start with `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_str_ops)]
+    /// use std::ffi::OsStr;
+    ///
+    /// assert_eq!(OsStr::new("--color").strip_prefix("--"), Some(OsStr::new("color")));
+    /// assert_eq!(OsStr::new("-c").strip_prefix("--"), None);
+    /// ```
+    #[unstable(feature = "os_str_str_ops", issue = "none")]
+    #[must_use = "this returns the remaining substring as a new slice, \
+                  without modifying the original"]
+    pub fn strip_prefix(&self, prefix: &str) -> Option<&OsStr> {
+        let rest = self.bytes().strip_prefix(prefix.as_bytes())?;
+        // SAFETY: `prefix` is a whole number of UTF-8 characters, so `rest`
+        // starts on a code point boundary.
+        Some(unsafe { OsStr::from_encoded_bytes_unchecked(rest) })
+    }
+
+    /// Returns the string before `suffix`, or `None` if it doesn't end with
+    /// `suffix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_str_ops)]
+    /// use std::ffi::OsStr;
+    ///
+    /// assert_eq!(OsStr::new("main.rs").strip_suffix(".rs"), Some(OsStr::new("main")));
+    /// assert_eq!(OsStr::new("main.c").strip_suffix(".rs"), None);
+    /// ```
+    #[unstable(feature = "os_str_str_ops", issue = "none")]
+    #[must_use = "this returns the remaining substring as a new slice, \
+                  without modifying the original"]
+    pub fn strip_suffix(&self, suffix: &str) -> Option<&OsStr> {
+        let rest = self.bytes().strip_suffix(suffix.as_bytes())?;
+        // SAFETY: `suffix` is a whole number of UTF-8 characters, so `rest`
+        // ends on a code point boundary.
+        Some(unsafe { OsStr::from_encoded_bytes_unchecked(rest) })
+    }
+
+    /// Splits the string on the first occurrence of `delimiter`, returning the
+    /// parts before and after it.
+    ///
+    /// Neither part needs to be valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_str_ops)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let (key, value) = OsStr::new("--out=a=b").split_once('=').unwrap();
+    /// assert_eq!(key, "--out");
+    /// assert_eq!(value, "a=b");
+    /// assert_eq!(OsStr::new("--verbose").split_once('='), None);
+    /// ```
+    #[unstable(feature = "os_str_str_ops", issue = "none")]
+    #[must_use = "this returns the split string as a pair of slices, \
+                  without modifying the original"]
+    pub fn split_once(&self, delimiter: char) -> Option<(&OsStr, &OsStr)> {
+        let mut buf = [0; 4];
+        let delimiter = delimiter.encode_utf8(&mut buf).as_bytes();
+        let index = self.bytes().windows(delimiter.len()).position(|w| w == delimiter)?;
+        Some(self.split_around(index, delimiter.len()))
+    }
+
+    /// Splits the string on the last occurrence of `delimiter`, returning the
+    /// parts before and after it.
+    ///
+    /// Neither part needs to be valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_str_ops)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let (stem, ext) = OsStr::new("archive.tar.gz").rsplit_once('.').unwrap();
+    /// assert_eq!(stem, "archive.tar");
+    /// assert_eq!(ext, "gz");
+    /// ```
+    #[unstable(feature = "os_str_str_ops", issue = "none")]
+    #[must_use = "this returns the split string as a pair of slices, \
+                  without modifying the original"]
+    pub fn rsplit_once(&self, delimiter: char) -> Option<(&OsStr, &OsStr)> {
+        let mut buf = [0; 4];
+        let delimiter = delimiter.encode_utf8(&mut buf).as_bytes();
+        let index = self.bytes().windows(delimiter.len()).rposition(|w| w == delimiter)?;
+        Some(self.split_around(index, delimiter.len()))
+    }
+
+    /// Splits off the `len` bytes of a UTF-8 delimiter found at `index`.
+    fn split_around(&self, index: usize, len: usize) -> (&OsStr, &OsStr) {
+        let bytes = self.bytes();
+        // SAFETY: UTF-8 is self-synchronizing, so a whole encoded character
+        // can only be found on code point boundaries.
+        unsafe {
+            (
+                OsStr::from_encoded_bytes_unchecked(&bytes[..index]),
+                OsStr::from_encoded_bytes_unchecked(&bytes[index + len..]),
+            )
+        }
+    }
+
+    /// Takes a substring based on a range of byte offsets into the string's
+    /// internal encoding.
+    ///
+    /// That encoding is unspecified and platform-specific, but any part of an
+    /// `OsStr` that is valid Unicode is always stored as UTF-8. Offsets made
+    /// from the [`len`] of a [`str`] that was found in the string, such as a
+    /// prefix checked with [`starts_with`], can therefore be used here.
+    ///
+    /// [`len`]: str::len
+    /// [`starts_with`]: OsStr::starts_with
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if either end of it would
+    /// split a character. Offsets next to an ASCII character are always
+    /// accepted, as are the start and end of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(os_str_slice)]
+    /// use std::ffi::OsStr;
+    ///
+    /// let arg = OsStr::new("-Iinclude");
+    /// assert_eq!(arg.slice_encoded_bytes(2..), "include");
+    /// assert_eq!(arg.slice_encoded_bytes(..2), "-I");
+    /// ```
+    #[unstable(feature = "os_str_slice", issue = "none")]
+    #[track_caller]
+    pub fn slice_encoded_bytes<R: ops::RangeBounds<usize>>(&self, range: R) -> &OsStr {
+        let bytes = self.bytes();
+        let ops::Range { start, end } = slice::range(range, ..bytes.len());
+        self.inner.check_public_boundary(start);
+        self.inner.check_public_boundary(end);
+        // SAFETY: both ends were just checked to be on a boundary.
+        unsafe { OsStr::from_encoded_bytes_unchecked(&bytes[start..end]) }
+    }
+
+    /// Creates an `OsStr` from bytes in its internal encoding.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be a valid encoding, for example a part of
+    /// [`OsStr::bytes`] that was split on code point boundaries.
+    #[inline]
+    unsafe fn from_encoded_bytes_unchecked(bytes: &[u8]) -> &OsStr {
+        Self::from_inner(unsafe { Slice::from_encoded_bytes_unchecked(bytes) })
+    }
 }
 
 #[stable(feature = "box_from_os_str", since = "1.17.0")]
@@ -177,3 +177,77 @@ fn into_rc() {
     assert_eq!(&*rc2, os_str);
     assert_eq!(&*arc2, os_str);
 }
+
+#[test]
+fn str_ops() {
+    let s = OsStr::new("--name=héllo=wörld");
+    assert!(s.starts_with("--"));
+    assert!(s.starts_with(""));
+    assert!(!s.starts_with("-n"));
+    assert!(s.ends_with("wörld"));
+    assert!(!s.ends_with("world"));
+
+    assert_eq!(s.strip_prefix("--"), Some(OsStr::new("name=héllo=wörld")));
+    assert_eq!(s.strip_prefix("-n"), None);
+    assert_eq!(s.strip_suffix("=wörld"), Some(OsStr::new("--name=héllo")));
+    assert_eq!(s.strip_suffix("héllo"), None);
+
+    assert_eq!(s.split_once('='), Some((OsStr::new("--name"), OsStr::new("héllo=wörld"))));
+    assert_eq!(s.rsplit_once('='), Some((OsStr::new("--name=héllo"), OsStr::new("wörld"))));
+    assert_eq!(s.split_once('é'), Some((OsStr::new("--name=h"), OsStr::new("llo=wörld"))));
+    assert_eq!(s.split_once(':'), None);
+    assert_eq!(OsStr::new("").split_once('='), None);
+}
+
+#[test]
+fn slice_encoded_bytes() {
+    let s = OsStr::new("-Ohé");
+    let rest = s.slice_encoded_bytes("-O".len()..);
+    assert_eq!(rest, "hé");
+    assert_eq!(s.slice_encoded_bytes(..), s);
+    assert_eq!(s.slice_encoded_bytes(..0), "");
+    assert_eq!(s.slice_encoded_bytes(s.len()..), "");
+    assert_eq!(s.slice_encoded_bytes(1..=2), "Oh");
+    assert_eq!(s.slice_encoded_bytes(3..), "é");
+}
+
+#[test]
+#[should_panic = "is not an OsStr boundary"]
+fn slice_encoded_bytes_mid_char() {
+    let _ = OsStr::new("é").slice_encoded_bytes(..1);
+}
+
+#[test]
+#[should_panic]
+fn slice_encoded_bytes_out_of_bounds() {
+    let _ = OsStr::new("abc").slice_encoded_bytes(..4);
+}
+
+#[test]
+#[cfg(unix)]
+fn str_ops_non_utf8() {
+    use crate::os::unix::ffi::OsStrExt;
+
+    let s = OsStr::from_bytes(b"--opt=\xFF\xFE");
+    assert!(s.starts_with("--opt"));
+    let (key, value) = s.split_once('=').unwrap();
+    assert_eq!(key, "--opt");
+    assert_eq!(value.as_bytes(), b"\xFF\xFE");
+    assert_eq!(s.strip_prefix("--").unwrap().as_bytes(), b"opt=\xFF\xFE");
+    assert_eq!(s.slice_encoded_bytes(7..).as_bytes(), b"\xFE");
+}
+
+#[test]
+#[cfg(windows)]
+fn str_ops_non_utf8() {
+    use crate::os::windows::ffi::{OsStrExt, OsStringExt};
+
+    // `--opt=` followed by an unpaired surrogate.
+    let wide: Vec<u16> = "--opt=".encode_utf16().chain([0xD800]).collect();
+    let s = OsString::from_wide(&wide);
+    assert!(s.starts_with("--opt"));
+    let (key, value) = s.split_once('=').unwrap();
+    assert_eq!(key, "--opt");
+    assert_eq!(value.encode_wide().collect::<Vec<_>>(), [0xD800]);
+    assert_eq!(s.slice_encoded_bytes(6..), value);
+}
@@ -139,6 +139,51 @@ fn test_buffered_reader_seek_relative() {
     assert_eq!(reader.fill_buf().ok(), Some(&[2, 3][..]));
 }
 
+#[test]
+fn test_buffered_reader_seek_relative_keeps_buffer() {
+    struct CountingSeeks<R> {
+        inner: R,
+        reads: usize,
+        seeks: usize,
+    }
+
+    impl<R: Read> Read for CountingSeeks<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reads += 1;
+            self.inner.read(buf)
+        }
+    }
+
+    impl<R: Seek> Seek for CountingSeeks<R> {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.seeks += 1;
+            self.inner.seek(pos)
+        }
+    }
+
+    // Records of a one-byte tag followed by three bytes of padding.
+    let data: Vec<u8> = (0..16).map(|i| if i % 4 == 0 { i / 4 } else { 0xff }).collect();
+    let inner = CountingSeeks { inner: io::Cursor::new(data), reads: 0, seeks: 0 };
+    let mut reader = BufReader::with_capacity(16, inner);
+
+    let mut tag = [0];
+    for i in 0..4 {
+        reader.read_exact(&mut tag).unwrap();
+        assert_eq!(tag[0], i);
+        reader.seek_relative(3).unwrap();
+    }
+    reader.seek_relative(-4).unwrap();
+    reader.seek_relative(-12).unwrap();
+    reader.read_exact(&mut tag).unwrap();
+    assert_eq!(tag[0], 0);
+    assert_eq!((reader.get_ref().reads, reader.get_ref().seeks), (1, 0));
+
+    // Leaving the buffered range falls back to a real seek.
+    reader.seek_relative(-2).unwrap_err();
+    assert_eq!(reader.get_ref().seeks, 1);
+    reader.seek_relative(i64::MIN).unwrap_err();
+}
+
 #[test]
 fn test_buffered_reader_stream_position() {
     let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
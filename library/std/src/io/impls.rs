@@ -330,7 +330,8 @@ fn consume(&mut self, amt: usize) {
 ///
 /// If the number of bytes to be written exceeds the size of the slice, write operations will
 /// return short writes: ultimately, `Ok(0)`; in this situation, `write_all` returns an error of
-/// kind `ErrorKind::WriteZero`.
+/// kind `ErrorKind::WriteZero`, after filling the slice with as much of the data as fits. The
+/// error message includes the number of bytes that were written.
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Write for &mut [u8] {
     #[inline]
@@ -362,11 +363,8 @@ fn is_write_vectored(&self) -> bool {
 
     #[inline]
     fn write_all(&mut self, data: &[u8]) -> io::Result<()> {
-        if self.write(data)? == data.len() {
-            Ok(())
-        } else {
-            Err(io::const_io_error!(ErrorKind::WriteZero, "failed to write whole buffer"))
-        }
+        let written = self.write(data)?;
+        if written == data.len() { Ok(()) } else { Err(slice_write_all_error(written, data.len())) }
     }
 
     #[inline]
@@ -375,6 +373,18 @@ fn flush(&mut self) -> io::Result<()> {
     }
 }
 
+// Kept out of line so that the allocating error path isn't inlined into
+// every caller of `<&mut [u8]>::write_all`. It tells the caller how much of
+// the data made it into the buffer before it ran out of room.
+#[cold]
+#[inline(never)]
+fn slice_write_all_error(written: usize, len: usize) -> io::Error {
+    io::Error::new(
+        ErrorKind::WriteZero,
+        format!("failed to write whole buffer: wrote {written} of {len} bytes"),
+    )
+}
+
 /// Write is implemented for `Vec<u8>` by appending to the vector.
 /// The vector will grow as needed.
 #[stable(feature = "rust1", since = "1.0.0")]
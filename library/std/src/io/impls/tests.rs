@@ -1,4 +1,17 @@
 use crate::io::prelude::*;
+use crate::io::ErrorKind;
+
+#[test]
+fn write_all_slice_reports_written() {
+    let mut buf = [0; 4];
+    let mut w = &mut buf[..];
+    w.write_all(b"ab").unwrap();
+    let err = w.write_all(b"cdef").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(err.to_string(), "failed to write whole buffer: wrote 2 of 4 bytes");
+    assert!(w.is_empty());
+    assert_eq!(&buf, b"abcd");
+}
 
 #[bench]
 fn bench_read_slice(b: &mut test::Bencher) {
@@ -284,6 +284,8 @@
 
 #[unstable(feature = "read_buf", issue = "78485")]
 pub use self::readbuf::{BorrowedBuf, BorrowedCursor};
+#[unstable(feature = "io_length_writer", issue = "none")]
+pub use self::util::LengthWriter;
 pub(crate) use error::const_io_error;
 
 mod buffered;
@@ -2612,6 +2614,40 @@ fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
         Ok(n)
     }
 
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize> {
+        // Don't call into inner reader at all at EOF because it may still block
+        if self.limit == 0 {
+            return Ok(0);
+        }
+
+        // Hand over as many whole buffers as fit within the limit. If not even
+        // the first non-empty one does, fall back to a plain read into its prefix.
+        let mut total = 0u64;
+        let mut fit = 0;
+        for buf in bufs.iter() {
+            let len = total.saturating_add(buf.len() as u64);
+            if len > self.limit {
+                break;
+            }
+            total = len;
+            fit += 1;
+        }
+
+        let n = if total == 0 && fit < bufs.len() {
+            // `self.limit` is smaller than this buffer's length, so it fits in a `usize`.
+            self.inner.read(&mut bufs[fit][..self.limit as usize])?
+        } else {
+            self.inner.read_vectored(&mut bufs[..fit])?
+        };
+        assert!(n as u64 <= self.limit, "number of read bytes exceeds limit");
+        self.limit -= n as u64;
+        Ok(n)
+    }
+
+    fn is_read_vectored(&self) -> bool {
+        self.inner.is_read_vectored()
+    }
+
     fn read_buf(&mut self, mut buf: BorrowedCursor<'_>) -> Result<()> {
         // Don't call into inner reader at all at EOF because it may still block
         if self.limit == 0 {
@@ -200,6 +200,36 @@ fn consume(&mut self, _amt: usize) {}
     assert_eq!(b"", R.take(0).fill_buf().unwrap());
 }
 
+#[test]
+fn take_read_vectored() {
+    let mut take = Cursor::new([1u8, 2, 3, 4, 5, 6, 7, 8]).take(5);
+    assert!(take.is_read_vectored());
+
+    // Both buffers fit under the limit and are filled in one call.
+    let (mut a, mut b) = ([0u8; 2], [0u8; 2]);
+    let n = take.read_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)]).unwrap();
+    assert_eq!(n, 4);
+    assert_eq!((a, b), ([1, 2], [3, 4]));
+    assert_eq!(take.limit(), 1);
+
+    // The first non-empty buffer is longer than what is left of the limit.
+    let (mut a, mut b) = ([0u8; 3], [0u8; 3]);
+    let n = take
+        .read_vectored(&mut [
+            IoSliceMut::new(&mut []),
+            IoSliceMut::new(&mut a),
+            IoSliceMut::new(&mut b),
+        ])
+        .unwrap();
+    assert_eq!(n, 1);
+    assert_eq!((a, b), ([5, 0, 0], [0, 0, 0]));
+    assert_eq!(take.limit(), 0);
+
+    let n = take.read_vectored(&mut [IoSliceMut::new(&mut a)]).unwrap();
+    assert_eq!(n, 0);
+    assert_eq!(take.into_inner().position(), 5);
+}
+
 fn cmp_bufread<Br1: BufRead, Br2: BufRead>(mut br1: Br1, mut br2: Br2, exp: &[u8]) {
     let mut cat = Vec::new();
     loop {
@@ -266,3 +266,80 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Sink").finish_non_exhaustive()
     }
 }
+
+/// A writer which discards all data written to it, but keeps track of how
+/// many bytes it was given.
+///
+/// This is useful for finding out how many bytes some serialization would
+/// produce without allocating a buffer to hold the result.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_length_writer)]
+/// use std::io::{LengthWriter, Write};
+///
+/// let mut w = LengthWriter::new();
+/// write!(w, "{} + {} = {}", 40, 2, 42).unwrap();
+/// assert_eq!(w.len(), 11);
+/// ```
+#[unstable(feature = "io_length_writer", issue = "none")]
+#[derive(Clone, Debug, Default)]
+pub struct LengthWriter {
+    len: u64,
+}
+
+impl LengthWriter {
+    /// Creates a new `LengthWriter` that hasn't been written to yet.
+    #[must_use]
+    #[unstable(feature = "io_length_writer", issue = "none")]
+    pub const fn new() -> LengthWriter {
+        LengthWriter { len: 0 }
+    }
+
+    /// Returns the total number of bytes written so far.
+    #[must_use]
+    #[unstable(feature = "io_length_writer", issue = "none")]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if no bytes have been written so far.
+    #[must_use]
+    #[unstable(feature = "io_length_writer", issue = "none")]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[unstable(feature = "io_length_writer", issue = "none")]
+impl Write for LengthWriter {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.len += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let total_len = bufs.iter().map(|b| b.len()).sum();
+        self.len += total_len as u64;
+        Ok(total_len)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.len += buf.len() as u64;
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
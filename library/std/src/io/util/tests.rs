@@ -1,8 +1,8 @@
 use crate::cmp::{max, min};
 use crate::io::prelude::*;
 use crate::io::{
-    copy, empty, repeat, sink, BorrowedBuf, BufWriter, Empty, Repeat, Result, SeekFrom, Sink,
-    DEFAULT_BUF_SIZE,
+    copy, empty, repeat, sink, BorrowedBuf, BufWriter, Empty, IoSlice, LengthWriter, Repeat,
+    Result, SeekFrom, Sink, DEFAULT_BUF_SIZE,
 };
 
 use crate::mem::MaybeUninit;
@@ -71,6 +71,22 @@ fn sink_sinks() {
     assert_eq!(s.by_ref().write(&[0; 1024]).unwrap(), 1024);
 }
 
+#[test]
+fn length_writer_counts() {
+    let mut w = LengthWriter::new();
+    assert!(w.is_empty());
+    assert_eq!(w.write(&[]).unwrap(), 0);
+    assert!(w.is_empty());
+    assert_eq!(w.write(&[0; 1024]).unwrap(), 1024);
+    w.write_all(&[0; 3]).unwrap();
+    let bufs = [IoSlice::new(&[0; 5]), IoSlice::new(&[]), IoSlice::new(&[0; 7])];
+    assert_eq!(w.write_vectored(&bufs).unwrap(), 12);
+    write!(w, "{:>4}", 1).unwrap();
+    w.flush().unwrap();
+    assert_eq!(w.len(), 1024 + 3 + 12 + 4);
+    assert!(!w.is_empty());
+}
+
 #[test]
 fn empty_reads() {
     let mut e = empty();
@@ -144,4 +160,5 @@ fn const_utils() {
     const _: Empty = empty();
     const _: Repeat = repeat(b'c');
     const _: Sink = sink();
+    const _: LengthWriter = LengthWriter::new();
 }
@@ -582,3 +582,24 @@ fn run_canonical_bat_script() {
     assert!(output.status.success());
     assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "Hello, fellow Rustaceans!");
 }
+
+// `ExitCode` has no public equality, so compare the unix representations.
+#[test]
+#[cfg(unix)]
+fn termination_report_exit_codes() {
+    use super::{ExitCode, Termination};
+    use crate::sys_common::AsInner;
+
+    fn assert_code(actual: ExitCode, expected: ExitCode) {
+        assert_eq!(actual.as_inner(), expected.as_inner());
+    }
+
+    assert_code(().report(), ExitCode::SUCCESS);
+    assert_code(ExitCode::from(42).report(), ExitCode::from(42));
+    assert_ne!(ExitCode::SUCCESS.as_inner(), ExitCode::FAILURE.as_inner());
+    assert_ne!(ExitCode::from(2).as_inner(), ExitCode::from(3).as_inner());
+
+    assert_code(Ok::<(), &str>(()).report(), ExitCode::SUCCESS);
+    assert_code(Ok::<ExitCode, &str>(ExitCode::from(3)).report(), ExitCode::from(3));
+    assert_code(Err::<ExitCode, &str>("custom error").report(), ExitCode::FAILURE);
+}
@@ -1,9 +1,10 @@
 use crate::{
-    panic,
+    panic, ptr,
     sync::OnceLock,
     sync::{
         atomic::{AtomicUsize, Ordering::SeqCst},
         mpsc::channel,
+        Arc, Barrier,
     },
     thread,
 };
@@ -28,6 +29,39 @@ fn sync_once_cell() {
     assert_eq!(ONCE_CELL.get(), Some(&92));
 }
 
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn sync_once_cell_racing_init() {
+    const N: usize = 8;
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static ONCE_CELL: OnceLock<usize> = OnceLock::new();
+    static SET_CELL: OnceLock<usize> = OnceLock::new();
+
+    let barrier = Arc::new(Barrier::new(N));
+    let threads: Vec<_> = (0..N)
+        .map(|i| {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                let value = ONCE_CELL.get_or_init(|| {
+                    CALLS.fetch_add(1, SeqCst);
+                    i
+                });
+                let set = SET_CELL.set(i).is_ok();
+                (value, set)
+            })
+        })
+        .collect();
+    let results: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+
+    // Exactly one initializer and one `set` won, and everyone saw the same value.
+    assert_eq!(CALLS.load(SeqCst), 1);
+    let value = ONCE_CELL.get().unwrap();
+    assert!(results.iter().all(|&(v, _)| ptr::eq(v, value)));
+    assert_eq!(results.iter().filter(|&&(_, set)| set).count(), 1);
+    assert!(SET_CELL.get().is_some());
+}
+
 #[test]
 fn sync_once_cell_get_mut() {
     let mut c = OnceLock::new();
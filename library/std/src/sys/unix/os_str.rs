@@ -201,6 +201,29 @@ pub fn from_str(s: &str) -> &Slice {
         Slice::from_u8_slice(s.as_bytes())
     }
 
+    #[inline]
+    pub unsafe fn from_encoded_bytes_unchecked(s: &[u8]) -> &Slice {
+        Slice::from_u8_slice(s)
+    }
+
+    /// Panics if `index` is not a place where an `OsStr` may be split.
+    ///
+    /// Any byte index would do here, but this matches the rules of the WTF-8
+    /// backend so that code splitting `OsStr`s behaves the same on all platforms.
+    #[track_caller]
+    #[inline]
+    pub fn check_public_boundary(&self, index: usize) {
+        if index == 0 || index == self.inner.len() {
+            return;
+        }
+        if index < self.inner.len()
+            && (self.inner[index - 1].is_ascii() || !is_utf8_continuation(self.inner[index]))
+        {
+            return;
+        }
+        panic!("byte index {index} is not an OsStr boundary");
+    }
+
     pub fn to_str(&self) -> Option<&str> {
         str::from_utf8(&self.inner).ok()
     }
@@ -270,3 +293,8 @@ pub fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
         self.inner.eq_ignore_ascii_case(&other.inner)
     }
 }
+
+#[inline]
+fn is_utf8_continuation(b: u8) -> bool {
+    (b as i8) < -0x40
+}
@@ -159,3 +159,23 @@ fn test_program_kind() {
         );
     }
 }
+
+#[test]
+fn test_set_arg_0() {
+    let mut cmd = Command::new(OsStr::new("prog"));
+    cmd.arg(OsStr::new("a"));
+    cmd.set_arg_0(OsStr::new("first"));
+    cmd.arg(OsStr::new("b"));
+    // Only the last override is used, and it can come after other arguments.
+    cmd.set_arg_0(OsStr::new("multicall-name"));
+
+    assert_eq!(cmd.get_program(), "prog");
+    assert_eq!(cmd.get_args().collect::<Vec<_>>(), ["a", "b"]);
+
+    let argv = cmd.get_argv();
+    assert_eq!(argv.len(), 4);
+    assert!(argv[3].is_null());
+    let argv: Vec<_> =
+        argv[..3].iter().map(|&arg| unsafe { CStr::from_ptr(arg) }.to_str().unwrap()).collect();
+    assert_eq!(argv, ["multicall-name", "a", "b"]);
+}
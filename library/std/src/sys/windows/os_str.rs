@@ -6,7 +6,7 @@
 use crate::mem;
 use crate::rc::Rc;
 use crate::sync::Arc;
-use crate::sys_common::wtf8::{Wtf8, Wtf8Buf};
+use crate::sys_common::wtf8::{self, Wtf8, Wtf8Buf};
 use crate::sys_common::{AsInner, FromInner, IntoInner};
 
 #[derive(Clone, Hash)]
@@ -155,6 +155,20 @@ pub fn from_str(s: &str) -> &Slice {
         unsafe { mem::transmute(Wtf8::from_str(s)) }
     }
 
+    #[inline]
+    pub unsafe fn from_encoded_bytes_unchecked(s: &[u8]) -> &Slice {
+        unsafe { mem::transmute(Wtf8::from_bytes_unchecked(s)) }
+    }
+
+    /// Panics if `index` is not a place where an `OsStr` may be split.
+    #[track_caller]
+    #[inline]
+    pub fn check_public_boundary(&self, index: usize) {
+        if !wtf8::is_code_point_boundary(&self.inner, index) {
+            panic!("byte index {index} is not an OsStr boundary");
+        }
+    }
+
     pub fn to_str(&self) -> Option<&str> {
         self.inner.as_str()
     }
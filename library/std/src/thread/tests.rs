@@ -331,6 +331,31 @@ fn sleep_ms_smoke() {
     thread::sleep(Duration::from_millis(2));
 }
 
+#[test]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn available_parallelism_respects_affinity() {
+    // Pin a fresh thread to a single CPU it is allowed to run on, so the test
+    // doesn't change the affinity of the test harness itself.
+    thread::spawn(|| unsafe {
+        let size = mem::size_of::<libc::cpu_set_t>();
+        let mut set: libc::cpu_set_t = mem::zeroed();
+        if libc::sched_getaffinity(0, size, &mut set) != 0 {
+            return;
+        }
+        let cpu = (0..libc::CPU_SETSIZE as usize).find(|&cpu| libc::CPU_ISSET(cpu, &set)).unwrap();
+
+        let mut single: libc::cpu_set_t = mem::zeroed();
+        libc::CPU_SET(cpu, &mut single);
+        if libc::sched_setaffinity(0, size, &single) != 0 {
+            return;
+        }
+
+        assert_eq!(thread::available_parallelism().unwrap().get(), 1);
+    })
+    .join()
+    .unwrap();
+}
+
 #[test]
 fn test_size_of_option_thread_id() {
     assert_eq!(mem::size_of::<Option<ThreadId>>(), mem::size_of::<ThreadId>());
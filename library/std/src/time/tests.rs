@@ -104,6 +104,15 @@ fn instant_duration_since_saturates() {
     assert_eq!((a - Duration::SECOND).duration_since(a), Duration::ZERO);
 }
 
+#[test]
+fn instant_elapsed_and_sub_saturate() {
+    let now = Instant::now();
+    let later = now + Duration::from_secs(3600);
+    assert_eq!(later.elapsed(), Duration::ZERO);
+    assert_eq!(now - later, Duration::ZERO);
+    assert_eq!(later - now, Duration::from_secs(3600));
+}
+
 #[test]
 fn instant_checked_duration_since_nopanic() {
     let now = Instant::now();
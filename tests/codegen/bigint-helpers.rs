@@ -0,0 +1,40 @@
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(bigint_helper_methods)]
+
+// The bigint helpers should lower to a single double-width multiply or an
+// add-with-overflow chain, rather than anything involving branches.
+
+// CHECK-LABEL: @widening_mul_u64
+#[no_mangle]
+pub fn widening_mul_u64(a: u64, b: u64) -> (u64, u64) {
+    // CHECK-NOT: br
+    // CHECK: mul nuw i128
+    // CHECK-NOT: br
+    // CHECK: ret
+    a.widening_mul(b)
+}
+
+// CHECK-LABEL: @carrying_mul_u64
+#[no_mangle]
+pub fn carrying_mul_u64(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    // CHECK-NOT: br
+    // CHECK: mul nuw i128
+    // CHECK: add nuw i128
+    // CHECK-NOT: br
+    // CHECK: ret
+    a.carrying_mul(b, carry)
+}
+
+// CHECK-LABEL: @add_u64x2
+#[no_mangle]
+pub fn add_u64x2(a: [u64; 2], b: [u64; 2]) -> [u64; 2] {
+    // CHECK-NOT: br
+    // CHECK: @llvm.uadd.with.overflow.i64
+    // CHECK-NOT: br
+    // CHECK: ret
+    let (lo, carry) = a[0].carrying_add(b[0], false);
+    let (hi, _) = a[1].carrying_add(b[1], carry);
+    [lo, hi]
+}
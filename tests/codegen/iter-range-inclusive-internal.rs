@@ -0,0 +1,27 @@
+// ignore-debug: the debug assertions get in the way
+// compile-flags: -O
+// only-x86_64
+
+#![crate_type = "lib"]
+
+// Internal iteration over a `RangeInclusive` goes through the specialized
+// `try_fold`, which handles the last element outside the loop, so the loop
+// itself is a plain counted loop that vectorizes.
+
+// CHECK-LABEL: @fill_for_each
+#[no_mangle]
+pub fn fill_for_each(v: &mut [u32; 1024], n: usize) {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK: store <{{[0-9]+}} x i32>
+    // CHECK-NOT: panic_bounds_check
+    // CHECK: ret void
+    (0..=n.min(1023)).for_each(|i| v[i] = i as u32);
+}
+
+// CHECK-LABEL: @mix_fold
+#[no_mangle]
+pub fn mix_fold(n: u32) -> u32 {
+    // CHECK: xor <{{[0-9]+}} x i32>
+    // CHECK: ret i32
+    (0..=n).map(|i| i.wrapping_mul(3) ^ 5).fold(0, u32::wrapping_add)
+}
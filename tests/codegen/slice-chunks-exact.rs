@@ -0,0 +1,53 @@
+// compile-flags: -O
+// ignore-debug: the debug assertions get in the way
+
+#![crate_type = "lib"]
+
+// Every chunk from the `*chunks_exact*` iterators is known to be exactly `n`
+// long, so indexing within a chunk needs no bounds checks.
+
+// CHECK-LABEL: @chunks_exact_sum
+#[no_mangle]
+pub fn chunks_exact_sum(data: &[u32]) -> u32 {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK: ret i32
+    let mut sum = 0;
+    for chunk in data.chunks_exact(4) {
+        sum += chunk[0] + chunk[1] + chunk[2] + chunk[3];
+    }
+    sum
+}
+
+// CHECK-LABEL: @chunks_exact_mut_fill
+#[no_mangle]
+pub fn chunks_exact_mut_fill(data: &mut [u8]) {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK: ret void
+    for chunk in data.chunks_exact_mut(3) {
+        chunk[0] = 1;
+        chunk[1] = 2;
+        chunk[2] = 3;
+    }
+}
+
+// CHECK-LABEL: @rchunks_exact_sum
+#[no_mangle]
+pub fn rchunks_exact_sum(data: &[u32]) -> u32 {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK: ret i32
+    let mut sum = 0;
+    for chunk in data.rchunks_exact(2) {
+        sum += chunk[0] * chunk[1];
+    }
+    sum
+}
+
+// CHECK-LABEL: @rchunks_exact_mut_swap
+#[no_mangle]
+pub fn rchunks_exact_mut_swap(data: &mut [u8]) {
+    // CHECK-NOT: panic_bounds_check
+    // CHECK: ret void
+    for chunk in data.rchunks_exact_mut(2) {
+        chunk.swap(0, 1);
+    }
+}
@@ -0,0 +1,31 @@
+// compile-flags: -O
+// only-64bit (because the LLVM type of i64 for usize shows up)
+
+#![crate_type = "lib"]
+
+// `fill` with a `Copy` value should become a single `memset` whenever the
+// value is a repeated byte, rather than an element-by-element loop.
+
+// CHECK-LABEL: @fill_u8
+#[no_mangle]
+pub fn fill_u8(s: &mut [u8], v: u8) {
+    // CHECK: tail call void @llvm.memset.{{.+}}(ptr {{.*}}%s.0, i8 %v, i64 %s.1, i1 false)
+    // CHECK: ret void
+    s.fill(v)
+}
+
+// CHECK-LABEL: @fill_bool
+#[no_mangle]
+pub fn fill_bool(s: &mut [bool]) {
+    // CHECK: tail call void @llvm.memset.{{.+}}(ptr {{.*}}%s.0, i8 1, i64 %s.1, i1 false)
+    // CHECK: ret void
+    s.fill(true)
+}
+
+// CHECK-LABEL: @fill_zero_u32
+#[no_mangle]
+pub fn fill_zero_u32(s: &mut [u32]) {
+    // CHECK: tail call void @llvm.memset.{{.+}}(ptr {{.*}}%s.0, i8 0, i64 %{{.+}}, i1 false)
+    // CHECK: ret void
+    s.fill(0)
+}
@@ -0,0 +1,31 @@
+// ignore-debug: the debug assertions get in the way
+// compile-flags: -O
+// only-x86_64
+
+#![crate_type = "lib"]
+
+// Zipped and mapped slice iterators are `TrustedLen`, so collecting or extending
+// reserves exactly once up front and the loop itself vectorizes.
+
+// CHECK-LABEL: @zip_map_collect
+#[no_mangle]
+pub fn zip_map_collect(a: &[u32], b: &[u32]) -> Vec<u32> {
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: call {{.*}}@__rust_alloc(
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: load <{{[0-9]+}} x i32>
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: ret void
+    a.iter().zip(b).map(|(x, y)| x + y).collect()
+}
+
+// CHECK-LABEL: @zip_map_extend
+#[no_mangle]
+pub fn zip_map_extend(v: &mut Vec<u32>, a: &[u32], b: &[u32]) {
+    // CHECK: do_reserve_and_handle
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: load <{{[0-9]+}} x i32>
+    // CHECK-NOT: do_reserve_and_handle
+    // CHECK: ret void
+    v.extend(a.iter().zip(b).map(|(x, y)| x * y))
+}
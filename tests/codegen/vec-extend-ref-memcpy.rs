@@ -0,0 +1,25 @@
+// compile-flags: -O
+// ignore-debug: the debug assertions get in the way
+
+#![crate_type = "lib"]
+
+// `Extend<&T>` for `Vec<T: Copy>` specializes on slice iterators, so extending
+// from `slice.iter()` is a single memcpy rather than an element-wise loop.
+
+// CHECK-LABEL: @extend_from_slice_iter
+#[no_mangle]
+pub fn extend_from_slice_iter(v: &mut Vec<u8>, s: &[u8]) {
+    // CHECK-NOT: load <{{[0-9]+}} x i8>
+    // CHECK: call void @llvm.memcpy
+    // CHECK-NOT: load <{{[0-9]+}} x i8>
+    // CHECK: ret void
+    v.extend(s.iter())
+}
+
+// CHECK-LABEL: @extend_from_slice_iter_u32
+#[no_mangle]
+pub fn extend_from_slice_iter_u32(v: &mut Vec<u32>, s: &[u32]) {
+    // CHECK: call void @llvm.memcpy
+    // CHECK: ret void
+    v.extend(s.iter())
+}
@@ -0,0 +1,34 @@
+// ignore-debug: the debug assertions get in the way
+// compile-flags: -O
+// only-x86_64
+
+#![crate_type = "lib"]
+
+// `Zip` over slice iterators uses `TrustedRandomAccess`, so the loop is driven
+// by a single index bounded by the shorter length: there are no per-element
+// bounds checks and the loop vectorizes like an index-based loop would.
+
+// CHECK-LABEL: @zip_dot
+#[no_mangle]
+pub fn zip_dot(a: &[i32], b: &[i32]) -> i32 {
+    // CHECK-NOT: panic
+    // CHECK: load <{{[0-9]+}} x i32>
+    // CHECK: mul <{{[0-9]+}} x i32>
+    // CHECK-NOT: panic
+    // CHECK: ret i32
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+// CHECK-LABEL: @zip_add_assign
+#[no_mangle]
+pub fn zip_add_assign(a: &mut [i32], b: &[i32]) {
+    // CHECK-NOT: panic
+    // CHECK: load <{{[0-9]+}} x i32>
+    // CHECK: add <{{[0-9]+}} x i32>
+    // CHECK: store <{{[0-9]+}} x i32>
+    // CHECK-NOT: panic
+    // CHECK: ret void
+    for (x, y) in a.iter_mut().zip(b) {
+        *x += *y;
+    }
+}